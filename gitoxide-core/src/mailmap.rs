@@ -20,7 +20,7 @@ pub fn verify(path: impl AsRef<Path>, format: OutputFormat, mut out: impl Write)
 
     let mut seen = HashSet::<(_, _)>::default();
     for entry in gix::mailmap::parse(&buf).filter_map(Result::ok) {
-        if !seen.insert((entry.old_email(), entry.old_name())) {
+        if !seen.insert((entry.old_email(), entry.old_name().map(ToOwned::to_owned))) {
             writeln!(
                 out,
                 "NOTE: entry ({:?}, {:?}) -> ({:?}, {:?}) is being overwritten",
@@ -24,9 +24,14 @@ pub fn decode<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
             context("<email>", terminated(take_until(&b"> "[..]), take(2usize))),
             context("<timestamp>", |i| {
                 terminated(take_until(SPACE), take(1usize))(i).and_then(|(i, v)| {
-                    btoi::<u32>(v)
-                        .map(|v| (i, v))
-                        .map_err(|_| nom::Err::Error(E::from_error_kind(i, nom::error::ErrorKind::MapRes)))
+                    btoi::<u32>(v).map(|v| (i, v)).map_err(|_| {
+                        let err = E::from_error_kind(i, nom::error::ErrorKind::MapRes);
+                        nom::Err::Error(if v.first() == Some(&b'-') {
+                            E::add_context(i, "timestamps before the unix epoch are unsupported", err)
+                        } else {
+                            err
+                        })
+                    })
                 })
             }),
             context("+|-", alt((tag(b"-"), tag(b"+")))),
@@ -40,8 +45,18 @@ pub fn decode<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
             context("MM", |i| {
                 take_while_m_n(2usize, 2, is_digit)(i).and_then(|(i, v)| {
                     btoi::<i32>(v)
-                        .map(|v| (i, v))
                         .map_err(|_| nom::Err::Error(E::from_error_kind(i, nom::error::ErrorKind::MapRes)))
+                        .and_then(|v| {
+                            if v < 60 {
+                                Ok((i, v))
+                            } else {
+                                Err(nom::Err::Error(E::add_context(
+                                    i,
+                                    "MM must be less than 60",
+                                    E::from_error_kind(i, nom::error::ErrorKind::Verify),
+                                )))
+                            }
+                        })
                 })
             }),
         )),
@@ -126,6 +141,37 @@ mod tests {
             );
         }
 
+        #[test]
+        fn tz_with_hours_beyond_a_day() {
+            assert_eq!(
+                decode(b"Sebastian Thiel <byronimo@gmail.com> 1528473343 +2400")
+                    .expect("parse to work")
+                    .1,
+                signature("Sebastian Thiel", "byronimo@gmail.com", 1528473343, Sign::Plus, 86400)
+            );
+        }
+
+        #[test]
+        fn tz_with_hours_and_minutes() {
+            assert_eq!(
+                decode(b"Sebastian Thiel <byronimo@gmail.com> 1528473343 +1345")
+                    .expect("parse to work")
+                    .1,
+                signature("Sebastian Thiel", "byronimo@gmail.com", 1528473343, Sign::Plus, 49500)
+            );
+        }
+
+        #[test]
+        fn invalid_tz_minutes() {
+            assert_eq!(
+                        decode(b"hello <> 12345 +0099")
+                            .map_err(to_bstr_err)
+                            .expect_err("parse fails as there are more than 59 minutes")
+                            .to_string(),
+                        "Parse error:\nVerify at: \nin section 'MM must be less than 60', at: \nin section 'MM', at: 99\nin section '<name> <<email>> <timestamp> <+|-><HHMM>', at: hello <> 12345 +0099\n"
+                    );
+        }
+
         #[test]
         fn empty_name_and_email() {
             assert_eq!(
@@ -145,6 +191,17 @@ mod tests {
                     );
         }
 
+        #[test]
+        fn negative_time() {
+            assert_eq!(
+                        decode(b"hello <> -1 +0000")
+                            .map_err(to_bstr_err)
+                            .expect_err("parse fails as negative times can't be represented")
+                            .to_string(),
+                        "Parse error:\nMapRes at: +0000\nin section 'timestamps before the unix epoch are unsupported', at: +0000\nin section '<timestamp>', at: -1 +0000\nin section '<name> <<email>> <timestamp> <+|-><HHMM>', at: hello <> -1 +0000\n"
+                    );
+        }
+
         #[test]
         fn invalid_time() {
             assert_eq!(
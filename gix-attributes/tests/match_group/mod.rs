@@ -107,6 +107,34 @@ mod ignore {
         );
     }
 
+    #[test]
+    fn last_match_wins_and_negations_override_earlier_excludes() {
+        let list = gix_attributes::PatternList::<Ignore>::from_bytes(
+            b"*.o\n!keep.o\nbuild/\n!build/\n",
+            "virtual-ignore-file",
+            None,
+        );
+        let is_ignored = |path: &str, is_dir: bool| {
+            let path = path.as_bytes().as_bstr();
+            list.pattern_matching_relative_path(
+                path,
+                path.rfind_byte(b'/').map(|p| p + 1),
+                Some(is_dir),
+                Case::Sensitive,
+            )
+            .map(|m| !m.pattern.is_negative())
+        };
+        assert_eq!(is_ignored("a.o", false), Some(true));
+        assert_eq!(is_ignored("dir/a.o", false), Some(true));
+        assert_eq!(
+            is_ignored("dir/keep.o", false),
+            Some(false),
+            "the later negation wins over the earlier exclude, and the caller applies it"
+        );
+        assert_eq!(is_ignored("build", true), Some(false));
+        assert_eq!(is_ignored("a.c", false), None, "no pattern has a say");
+    }
+
     fn pattern_to_match(pattern: &gix_glob::Pattern, sequence_number: usize) -> Match<'_, ()> {
         Match {
             pattern,
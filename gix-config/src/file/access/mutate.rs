@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 
-use bstr::BStr;
+use bstr::{BStr, BString, ByteSlice, ByteVec};
 use gix_features::threading::OwnShared;
 
 use crate::{
-    file::{self, rename_section, write::ends_with_newline, MetadataFilter, SectionBodyIdsLut, SectionId, SectionMut},
+    file::{self, merge, rename_section, write::ends_with_newline, MetadataFilter, SectionId, SectionMut},
     lookup,
     parse::{section, Event, FrontMatterEvents},
+    value::{normalize_bstr, normalize_bstring},
     File,
 };
 
@@ -224,29 +225,7 @@ impl<'event> File<'event> {
         self.section_order
             .remove(self.section_order.iter().position(|v| *v == id)?);
         let section = self.sections.remove(&id)?;
-        let lut = self
-            .section_lookup_tree
-            .get_mut(&section.header.name)
-            .expect("lookup cache still has name to be deleted");
-        // NOTE: this leaves empty lists in the data structure which our code now has to deal with.
-        for entry in lut {
-            match section.header.subsection_name.as_deref() {
-                Some(subsection_name) => {
-                    if let SectionBodyIdsLut::NonTerminal(map) = entry {
-                        if let Some(ids) = map.get_mut(subsection_name) {
-                            ids.remove(ids.iter().position(|v| *v == id).expect("present"));
-                            break;
-                        }
-                    }
-                }
-                None => {
-                    if let SectionBodyIdsLut::Terminal(ids) = entry {
-                        ids.remove(ids.iter().position(|v| *v == id).expect("present"));
-                        break;
-                    }
-                }
-            }
-        }
+        self.remove_section_id_from_lookup(id, &section.header);
         Some(section)
     }
 
@@ -289,6 +268,9 @@ impl<'event> File<'event> {
 
     /// Renames the section with `name` and `subsection_name`, modifying the last matching section
     /// to use `new_name` and `new_subsection_name`.
+    ///
+    /// It's an error if a section with `new_name` and `new_subsection_name` already exists. Use
+    /// [`rename_section_and_merge()`][Self::rename_section_and_merge()] to allow it.
     pub fn rename_section<'a>(
         &mut self,
         name: impl AsRef<str>,
@@ -296,13 +278,25 @@ impl<'event> File<'event> {
         new_name: impl Into<Cow<'event, str>>,
         new_subsection_name: impl Into<Option<Cow<'event, BStr>>>,
     ) -> Result<(), rename_section::Error> {
-        let id = self
-            .section_ids_by_name_and_subname(name.as_ref(), subsection_name.into())?
-            .rev()
-            .next()
-            .expect("list of sections were empty, which violates invariant");
-        let section = self.sections.get_mut(&id).expect("known section-id");
-        section.header = section::Header::new(new_name, new_subsection_name)?;
+        let id = self.last_section_id_to_rename(name.as_ref(), subsection_name.into())?;
+        let header = section::Header::new(new_name, new_subsection_name)?;
+        self.ensure_no_other_section_has_header(id, &header)?;
+        self.rename_section_by_id(id, header);
+        Ok(())
+    }
+
+    /// Like [`rename_section()`][Self::rename_section()], but allows sections with `new_name` and `new_subsection_name`
+    /// to exist already. The renamed section keeps its position, so its values are merged with the ones of these
+    /// sections when reading, just like `git config --rename-section` does.
+    pub fn rename_section_and_merge<'a>(
+        &mut self,
+        name: impl AsRef<str>,
+        subsection_name: impl Into<Option<&'a BStr>>,
+        new_name: impl Into<Cow<'event, str>>,
+        new_subsection_name: impl Into<Option<Cow<'event, BStr>>>,
+    ) -> Result<(), rename_section::Error> {
+        let id = self.last_section_id_to_rename(name.as_ref(), subsection_name.into())?;
+        self.rename_section_by_id(id, section::Header::new(new_name, new_subsection_name)?);
         Ok(())
     }
 
@@ -311,6 +305,8 @@ impl<'event> File<'event> {
     ///
     /// Note that the otherwise unused [`lookup::existing::Error::KeyMissing`] variant is used to indicate
     /// that the `filter` rejected all candidates, leading to no section being renamed after all.
+    /// Just like with [`rename_section()`][Self::rename_section()], it's an error if a section with `new_name`
+    /// and `new_subsection_name` already exists.
     pub fn rename_section_filter<'a>(
         &mut self,
         name: impl AsRef<str>,
@@ -324,11 +320,66 @@ impl<'event> File<'event> {
             .rev()
             .find(|id| filter(self.sections.get(id).expect("each id has a section").meta()))
             .ok_or(rename_section::Error::Lookup(lookup::existing::Error::KeyMissing))?;
-        let section = self.sections.get_mut(&id).expect("known section-id");
-        section.header = section::Header::new(new_name, new_subsection_name)?;
+        let header = section::Header::new(new_name, new_subsection_name)?;
+        self.ensure_no_other_section_has_header(id, &header)?;
+        self.rename_section_by_id(id, header);
         Ok(())
     }
 
+    /// Merge all values of `other` into ourselves, with `precedence` determining what happens to keys we already have.
+    ///
+    /// Keys are identified by section name, subsection name and key name, no matter in how many sections they appear.
+    /// Values are added to our last section with the same name and subsection name, or to a new section if there is none.
+    ///
+    /// Unlike with [`append()`][Self::append()], the result doesn't retain the sections of `other` as they are,
+    /// so comments, whitespace and metadata of `other` are lost.
+    pub fn merge(&mut self, other: &File<'_>, precedence: merge::Precedence) -> &mut Self {
+        for (header, keys) in values_by_key(other) {
+            let name = header.name().to_str_lossy();
+            let subsection_name = header.subsection_name();
+            let mut ids: Vec<_> = self
+                .section_ids_by_name_and_subname(name.as_ref(), subsection_name)
+                .map(|ids| ids.collect())
+                .unwrap_or_default();
+            for (key, values) in keys {
+                match precedence {
+                    merge::Precedence::Overwrite => {
+                        for id in &ids {
+                            self.section_mut_by_id(*id)
+                                .expect("id from lookup is valid")
+                                .remove_value(key, |_| true);
+                        }
+                    }
+                    merge::Precedence::FillMissing => {
+                        if ids.iter().any(|id| self.sections[id].contains_key(key)) {
+                            continue;
+                        }
+                    }
+                    merge::Precedence::Additive => {}
+                }
+                let id = match ids.last() {
+                    Some(id) => *id,
+                    None => {
+                        let id = self
+                            .new_section(
+                                name.clone().into_owned(),
+                                subsection_name.map(|name| Cow::Owned(name.to_owned())),
+                            )
+                            .expect("headers of other files are valid")
+                            .id();
+                        ids.push(id);
+                        id
+                    }
+                };
+                let mut section = self.section_mut_by_id(id).expect("id is valid");
+                for value in values {
+                    section.push(key.to_owned(), value.as_deref());
+                }
+            }
+        }
+        self
+    }
+
     /// Append another File to the end of ourselves, without losing any information.
     pub fn append(&mut self, other: Self) -> &mut Self {
         self.append_or_insert(other, None)
@@ -385,3 +436,54 @@ impl<'event> File<'event> {
         self
     }
 }
+
+type ValuesByKey<'a> = Vec<(&'a section::Key<'a>, Vec<Option<Cow<'a, BStr>>>)>;
+
+/// Collect all normalized values of `file` by header and key, in order of their first occurrence, with implicit values being `None`.
+fn values_by_key<'a>(file: &'a File<'_>) -> Vec<(&'a section::Header<'a>, ValuesByKey<'a>)> {
+    let mut out = Vec::<(&section::Header<'_>, ValuesByKey<'_>)>::new();
+    for section in file.sections() {
+        let header = section.header();
+        let pos = out
+            .iter()
+            .position(|(h, _)| {
+                h.name().eq_ignore_ascii_case(header.name()) && h.subsection_name() == header.subsection_name()
+            })
+            .unwrap_or_else(|| {
+                out.push((header, Vec::new()));
+                out.len() - 1
+            });
+        let keys = &mut out[pos].1;
+        let mut push = |key: &'a section::Key<'a>, value| match keys.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, values)) => values.push(value),
+            None => keys.push((key, vec![value])),
+        };
+
+        let mut current = None;
+        let mut partial_value = BString::default();
+        for event in section.as_ref() {
+            match event {
+                Event::SectionKey(key) => current = Some((key, false)),
+                Event::KeyValueSeparator => {
+                    if let Some((_, has_separator)) = current.as_mut() {
+                        *has_separator = true;
+                    }
+                }
+                Event::Value(v) => {
+                    if let Some((key, has_separator)) = current.take() {
+                        push(key, has_separator.then(|| normalize_bstr(v.as_ref())));
+                    }
+                }
+                Event::ValueNotDone(v) => partial_value.push_str(v.as_ref()),
+                Event::ValueDone(v) => {
+                    if let Some((key, _)) = current.take() {
+                        partial_value.push_str(v.as_ref());
+                        push(key, Some(normalize_bstring(std::mem::take(&mut partial_value))));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    out
+}
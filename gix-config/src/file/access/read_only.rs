@@ -262,6 +262,23 @@ impl<'event> File<'event> {
         })
     }
 
+    /// Returns true if there is at least one section with the given `name` and optional `subsection_name`.
+    ///
+    /// This uses the same lookup as [`section()`][Self::section()] and [`section_mut()`][Self::section_mut()], so `name`
+    /// is case-insensitive while `subsection_name` is not.
+    #[must_use]
+    pub fn has_section(&self, name: impl AsRef<str>, subsection_name: Option<&BStr>) -> bool {
+        self.section_ids_by_name_and_subname(name.as_ref(), subsection_name)
+            .map_or(false, |ids| ids.len() != 0)
+    }
+
+    /// Returns the number of sections with the given `name`, including all of its subsections, similar to what
+    /// [`sections_by_name()`][Self::sections_by_name()] would yield.
+    #[must_use]
+    pub fn section_count(&self, name: impl AsRef<str>) -> usize {
+        self.section_ids_by_name(name.as_ref()).map_or(0, Iterator::count)
+    }
+
     /// Returns the number of values in the config, no matter in which section.
     ///
     /// For example, a config with multiple empty sections will return 0.
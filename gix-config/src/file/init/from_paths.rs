@@ -20,6 +20,9 @@ impl File<'static> {
     /// Load the single file at `path` with `source` without following include directives.
     ///
     /// Note that the path will be checked for ownership to derive trust.
+    ///
+    /// To follow `include.path` and `includeIf.<condition>.path` directives, use [`from_paths_metadata()`][Self::from_paths_metadata()]
+    /// with [`includes::Options::follow()`][crate::file::includes::Options::follow()] instead.
     pub fn from_path_no_includes(path: impl Into<std::path::PathBuf>, source: crate::Source) -> Result<Self, Error> {
         let path = path.into();
         let trust = gix_sec::Trust::from_path_ownership(&path)?;
@@ -1,3 +1,4 @@
+use bstr::BString;
 use gix_features::threading::OwnShared;
 
 use crate::{
@@ -30,6 +31,8 @@ impl<'a> File<'a> {
 
     /// Instantiate a new `File` from given `input`, associating each section and their values with
     /// `meta`-data, while respecting `options`.
+    ///
+    /// If `meta` has a path, it will be used as origin of parse errors.
     pub fn from_bytes_no_includes(
         input: &'a [u8],
         meta: impl Into<OwnShared<Metadata>>,
@@ -37,11 +40,22 @@ impl<'a> File<'a> {
     ) -> Result<Self, Error> {
         let meta = meta.into();
         Ok(Self::from_parse_events_no_includes(
-            parse::Events::from_bytes(input, options.to_event_filter())?,
+            parse::Events::from_bytes(input, options.to_event_filter())
+                .map_err(|err| with_path_as_origin(err, &meta))?,
             meta,
         ))
     }
 
+    /// Instantiate a new `File` from given `input` like [`from_bytes_no_includes()`][Self::from_bytes_no_includes()]
+    /// with [API metadata][Metadata::api()] and default options, but with parse errors mentioning `origin`, like
+    /// the name of the file that `input` was read from.
+    pub fn from_bytes_with_origin(input: &'a [u8], origin: impl Into<BString>) -> Result<Self, Error> {
+        Ok(Self::from_parse_events_no_includes(
+            parse::Events::from_bytes(input, None).map_err(|err| err.with_origin(origin))?,
+            Metadata::api(),
+        ))
+    }
+
     /// Instantiate a new `File` from given `events`, associating each section and their values with
     /// `meta`-data.
     pub fn from_parse_events_no_includes(
@@ -70,13 +84,17 @@ impl File<'static> {
     /// Instantiate a new fully-owned `File` from given `input` (later reused as buffer when resolving includes),
     /// associating each section and their values with `meta`-data, while respecting `options`, and
     /// following includes as configured there.
+    ///
+    /// If `meta` has a path, it will be used as origin of parse errors.
     pub fn from_bytes_owned(
         input_and_buf: &mut Vec<u8>,
         meta: impl Into<OwnShared<Metadata>>,
         options: Options<'_>,
     ) -> Result<Self, Error> {
+        let meta = meta.into();
         let mut config = Self::from_parse_events_no_includes(
-            parse::Events::from_bytes_owned(input_and_buf, options.to_event_filter()).map_err(Error::from)?,
+            parse::Events::from_bytes_owned(input_and_buf, options.to_event_filter())
+                .map_err(|err| with_path_as_origin(err, &meta))?,
             meta,
         );
 
@@ -84,3 +102,10 @@ impl File<'static> {
         Ok(config)
     }
 }
+
+fn with_path_as_origin(err: parse::Error, meta: &Metadata) -> parse::Error {
+    match meta.path.as_deref() {
+        Some(path) => err.with_origin(gix_path::into_bstr(path).into_owned()),
+        None => err,
+    }
+}
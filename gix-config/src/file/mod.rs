@@ -27,6 +27,8 @@ pub mod section;
 
 ///
 pub mod rename_section {
+    use bstr::BString;
+
     /// The error returned by [`File::rename_section(…)`][crate::File::rename_section()].
     #[derive(Debug, thiserror::Error)]
     #[allow(missing_docs)]
@@ -35,6 +37,24 @@ pub mod rename_section {
         Lookup(#[from] crate::lookup::existing::Error),
         #[error(transparent)]
         Section(#[from] crate::parse::section::header::Error),
+        #[error("A section named '{name}' with subsection {subsection_name:?} exists already and would be merged with the renamed one")]
+        SectionExists {
+            name: BString,
+            subsection_name: Option<BString>,
+        },
+    }
+}
+
+///
+pub mod set_leading_whitespace {
+    use bstr::BString;
+
+    /// The error returned by [`SectionMut::try_set_leading_whitespace(…)`][crate::file::SectionMut::try_set_leading_whitespace()].
+    #[derive(Debug, thiserror::Error)]
+    #[allow(missing_docs)]
+    pub enum Error {
+        #[error("Leading whitespace must only contain whitespace characters, got {whitespace:?}")]
+        NonWhitespace { whitespace: BString },
     }
 }
 
@@ -51,6 +71,20 @@ pub mod set_raw_value {
     }
 }
 
+///
+pub mod merge {
+    /// Determines what happens to keys that exist in both files when using [`File::merge()`][crate::File::merge()].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub enum Precedence {
+        /// All of our values of a key are replaced by the values of the other file.
+        Overwrite,
+        /// Values of the other file are only added for keys that we don't have yet.
+        FillMissing,
+        /// All values of the other file are added after ours, so that keys with multiple values accumulate the values of both files.
+        Additive,
+    }
+}
+
 /// Additional information about a section.
 #[derive(Clone, Debug, PartialOrd, PartialEq, Ord, Eq, Hash)]
 pub struct Metadata {
@@ -142,6 +142,48 @@ impl<'a, 'event> SectionMut<'a, 'event> {
         }
     }
 
+    /// Sets `key` to the single `value`, similar to `git config --replace-all`, or adds the new value if there is none.
+    /// Returns all previous values in order and normalized, which is empty if the value was added.
+    ///
+    /// The first occurrence of `key` is updated in place while keeping its surrounding whitespace, and all other occurrences
+    /// are removed like with [`remove_value()`][Self::remove_value()]. Use [`push()`][Self::push()] to add another value instead.
+    pub fn set_single<'b>(&mut self, key: Key<'event>, value: impl Into<&'b BStr>) -> Vec<Cow<'event, BStr>> {
+        let value = value.into();
+        let mut key_ranges = self.section.key_ranges_by(&key).into_iter();
+        let first = match key_ranges.next() {
+            None => {
+                self.push(key, Some(value));
+                return Vec::new();
+            }
+            Some(range) => range,
+        };
+        let mut previous: Vec<_> = key_ranges
+            .rev()
+            .map(|range| self.remove_internal(range, true))
+            .collect();
+
+        let events = &self.section.body.0;
+        let has_separator = events[first.clone()]
+            .iter()
+            .any(|e| matches!(e, Event::KeyValueSeparator));
+        if has_separator {
+            let value_start = events[first.clone()]
+                .iter()
+                .position(|e| matches!(e, Event::Value(_) | Event::ValueNotDone(_)))
+                .map_or(first.end, |pos| first.start + pos);
+            previous.push(self.remove_internal(value_start..first.end, false));
+            self.section
+                .body
+                .0
+                .insert(value_start, Event::Value(escape_value(value).into()));
+        } else {
+            previous.push(self.remove_internal(first.clone(), false));
+            self.set_internal(Index(first.start), key, value);
+        }
+        previous.reverse();
+        previous.into_iter().map(normalize).collect()
+    }
+
     /// Removes the latest value by key and returns it, if it exists.
     pub fn remove(&mut self, key: impl AsRef<str>) -> Option<Cow<'event, BStr>> {
         let key = Key::from_str_unchecked(key.as_ref());
@@ -149,6 +191,35 @@ impl<'a, 'event> SectionMut<'a, 'event> {
         Some(self.remove_internal(key_range, true))
     }
 
+    /// Removes all values of `key` for which `predicate` returns `true` when called with the normalized value,
+    /// and returns the removed values in order. Other values of `key` are left intact.
+    ///
+    /// Like with [`remove()`][Self::remove()], the whitespace preceding the removed key value pairs and the newline following them
+    /// are removed as well.
+    pub fn remove_value(
+        &mut self,
+        key: impl AsRef<str>,
+        mut predicate: impl FnMut(&BStr) -> bool,
+    ) -> Vec<Cow<'event, BStr>> {
+        let key = Key::from_str_unchecked(key.as_ref());
+        let mut removed = Vec::new();
+        for key_range in self.section.key_ranges_by(&key).into_iter().rev() {
+            let value = self.section.0[key_range.clone()]
+                .iter()
+                .fold(BString::default(), |mut acc, e| {
+                    if let Event::Value(v) | Event::ValueNotDone(v) | Event::ValueDone(v) = e {
+                        acc.push_str(v.as_ref());
+                    }
+                    acc
+                });
+            if predicate(normalize_bstring(value).as_ref()) {
+                removed.push(self.remove_internal(key_range, true));
+            }
+        }
+        removed.reverse();
+        removed
+    }
+
     /// Adds a new line event. Note that you don't need to call this unless
     /// you've disabled implicit newlines.
     pub fn push_newline(&mut self) -> &mut Self {
@@ -180,16 +251,28 @@ impl<'a, 'event> SectionMut<'a, 'event> {
     /// # Panics
     ///
     /// If non-whitespace characters are used. This makes the method only suitable for validated
-    /// or known input.
+    /// or known input, use [`try_set_leading_whitespace()`][Self::try_set_leading_whitespace()] otherwise.
     pub fn set_leading_whitespace(&mut self, whitespace: Option<Cow<'event, BStr>>) -> &mut Self {
-        assert!(
-            whitespace
-                .as_deref()
-                .map_or(true, |ws| ws.iter().all(|b| b.is_ascii_whitespace())),
-            "input whitespace must only contain whitespace characters."
-        );
+        self.try_set_leading_whitespace(whitespace)
+            .expect("input whitespace must only contain whitespace characters.")
+    }
+
+    /// Like [`set_leading_whitespace()`][Self::set_leading_whitespace()], but returns an error if `whitespace`
+    /// contains non-whitespace characters, leaving the previously set whitespace unchanged.
+    pub fn try_set_leading_whitespace(
+        &mut self,
+        whitespace: Option<Cow<'event, BStr>>,
+    ) -> Result<&mut Self, file::set_leading_whitespace::Error> {
+        if let Some(ws) = whitespace
+            .as_deref()
+            .filter(|ws| !ws.iter().all(|b| b.is_ascii_whitespace()))
+        {
+            return Err(file::set_leading_whitespace::Error::NonWhitespace {
+                whitespace: ws.to_owned(),
+            });
+        }
         self.whitespace.pre_key = whitespace;
-        self
+        Ok(self)
     }
 
     /// Returns the whitespace this section will insert before the
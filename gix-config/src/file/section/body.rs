@@ -90,6 +90,37 @@ impl<'event> Body<'event> {
         })
     }
 
+    /// Returns an iterator visiting all key-value pairs in order, with the values being normalized.
+    ///
+    /// Keys with multiple values are yielded once for each of their values, and implicit values, i.e. those without
+    /// a key-value separator, are represented by an empty value.
+    pub fn iter(&self) -> impl Iterator<Item = (&Key<'event>, Cow<'_, BStr>)> {
+        let mut events = self.0.iter();
+        std::iter::from_fn(move || {
+            let mut key = None;
+            let mut partial_value = BString::default();
+            for event in events.by_ref() {
+                match event {
+                    Event::SectionKey(k) => key = Some(k),
+                    Event::Value(v) => {
+                        if let Some(key) = key {
+                            return Some((key, normalize_bstr(v.as_ref())));
+                        }
+                    }
+                    Event::ValueNotDone(v) => partial_value.push_str(v.as_ref()),
+                    Event::ValueDone(v) => {
+                        if let Some(key) = key {
+                            partial_value.push_str(v.as_ref());
+                            return Some((key, normalize_bstring(partial_value)));
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            None
+        })
+    }
+
     /// Returns true if the section contains the provided key.
     #[must_use]
     pub fn contains_key(&self, key: impl AsRef<str>) -> bool {
@@ -114,6 +145,24 @@ impl<'event> Body<'event> {
     pub fn is_void(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Return all events of this section body in order of occurrence, which is useful for tools that need to see
+    /// whitespace and comments as well, for instance to reformat a configuration file faithfully.
+    ///
+    /// The section header isn't part of the body, see [`Section::header()`][crate::file::Section::header()] instead.
+    /// Writing all events in order reproduces the body byte by byte. The parser produces sequences with these properties,
+    /// which code that creates new events to be written should uphold as well:
+    ///
+    /// * Each [`SectionKey`][Event::SectionKey] is followed by exactly one value, which can be preceded by a
+    ///   [`KeyValueSeparator`][Event::KeyValueSeparator], with optional [`Whitespace`][Event::Whitespace] around it.
+    /// * A value is either a single [`Value`][Event::Value], or any amount of [`ValueNotDone`][Event::ValueNotDone]
+    ///   each followed by a [`Newline`][Event::Newline], ended by a [`ValueDone`][Event::ValueDone].
+    /// * Keys without separator are followed by an empty `Value` and are considered implicit booleans. Keys with separator
+    ///   but no value, like `key =`, have an empty `Value` as well.
+    /// * [`Comment`][Event::Comment]s extend to the end of their line, so only a `Newline` may follow them.
+    pub fn events(&self) -> &[Event<'event>] {
+        &self.0
+    }
 }
 
 impl<'event> Body<'event> {
@@ -159,6 +208,33 @@ impl<'event> Body<'event> {
     }
 }
 
+impl<'event> Body<'event> {
+    /// Returns the ranges of all events of each `key`, from the key itself up to and including its last value event,
+    /// in order of occurrence.
+    pub(crate) fn key_ranges_by(&self, key: &Key<'_>) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut current: Option<Range<usize>> = None;
+        for (i, e) in self.0.iter().enumerate() {
+            match e {
+                Event::SectionKey(k) => {
+                    ranges.extend(current.take());
+                    if k == key {
+                        current = Some(i..i + 1);
+                    }
+                }
+                Event::Value(_) | Event::ValueNotDone(_) | Event::ValueDone(_) => {
+                    if let Some(range) = current.as_mut() {
+                        range.end = i + 1;
+                    }
+                }
+                _ => (),
+            }
+        }
+        ranges.extend(current);
+        ranges
+    }
+}
+
 /// An owning iterator of a section body. Created by [`Body::into_iter`], yielding
 /// un-normalized (`key`, `value`) pairs.
 // TODO: tests
@@ -113,6 +113,112 @@ impl<'event> File<'event> {
         new_section_id
     }
 
+    /// Returns the id of the last section with `name` and `subsection_name`, which is the one to rename.
+    pub(crate) fn last_section_id_to_rename(
+        &self,
+        name: &str,
+        subsection_name: Option<&BStr>,
+    ) -> Result<SectionId, lookup::existing::Error> {
+        Ok(self
+            .section_ids_by_name_and_subname(name, subsection_name)?
+            .rev()
+            .next()
+            .expect("list of sections were empty, which violates invariant"))
+    }
+
+    /// Fail if a section other than the one with `id` uses the name and subsection name of `header`.
+    pub(crate) fn ensure_no_other_section_has_header(
+        &self,
+        id: SectionId,
+        header: &section::Header<'_>,
+    ) -> Result<(), file::rename_section::Error> {
+        let exists = self
+            .section_ids_by_name_and_subname(header.name.as_ref(), header.subsection_name.as_deref())
+            .map_or(false, |mut ids| ids.any(|other| other != id));
+        if exists {
+            return Err(file::rename_section::Error::SectionExists {
+                name: header.name.as_ref().into(),
+                subsection_name: header.subsection_name.as_deref().map(ToOwned::to_owned),
+            });
+        }
+        Ok(())
+    }
+
+    /// Replace the header of the section with `id` with `header`, and update all lookup structures so the section
+    /// can be found by its new name. The body and the position of the section remain unchanged.
+    pub(crate) fn rename_section_by_id(&mut self, id: SectionId, header: section::Header<'event>) {
+        let section = self.sections.get_mut(&id).expect("known section-id");
+        let previous_header = std::mem::replace(&mut section.header, header);
+        self.remove_section_id_from_lookup(id, &previous_header);
+
+        let order_of = {
+            let section_order = &self.section_order;
+            move |section_id| {
+                section_order
+                    .iter()
+                    .position(|id| *id == section_id)
+                    .expect("section exists")
+            }
+        };
+        let order = order_of(id);
+        let header = &self.sections[&id].header;
+        let lookup = self.section_lookup_tree.entry(header.name.clone()).or_default();
+        let insert_sorted = |ids: &mut Vec<SectionId>| {
+            let pos = ids
+                .iter()
+                .position(|other| order_of(*other) > order)
+                .unwrap_or(ids.len());
+            ids.insert(pos, id);
+        };
+        match header.subsection_name.clone() {
+            Some(subsection_name) => match lookup.iter_mut().find_map(|node| match node {
+                SectionBodyIdsLut::NonTerminal(subsections) => Some(subsections),
+                SectionBodyIdsLut::Terminal(_) => None,
+            }) {
+                Some(subsections) => insert_sorted(subsections.entry(subsection_name).or_default()),
+                None => {
+                    let mut map = HashMap::new();
+                    map.insert(subsection_name, vec![id]);
+                    lookup.push(SectionBodyIdsLut::NonTerminal(map));
+                }
+            },
+            None => match lookup.iter_mut().find_map(|node| match node {
+                SectionBodyIdsLut::Terminal(ids) => Some(ids),
+                SectionBodyIdsLut::NonTerminal(_) => None,
+            }) {
+                Some(ids) => insert_sorted(ids),
+                None => lookup.push(SectionBodyIdsLut::Terminal(vec![id])),
+            },
+        }
+    }
+
+    /// Remove `id` from the lookup entries of the section with `header`.
+    pub(crate) fn remove_section_id_from_lookup(&mut self, id: SectionId, header: &section::Header<'event>) {
+        let lut = self
+            .section_lookup_tree
+            .get_mut(&header.name)
+            .expect("lookup cache still has name to be deleted");
+        // NOTE: this leaves empty lists in the data structure which our code now has to deal with.
+        for entry in lut {
+            match header.subsection_name.as_deref() {
+                Some(subsection_name) => {
+                    if let SectionBodyIdsLut::NonTerminal(map) = entry {
+                        if let Some(ids) = map.get_mut(subsection_name) {
+                            ids.remove(ids.iter().position(|v| *v == id).expect("present"));
+                            break;
+                        }
+                    }
+                }
+                None => {
+                    if let SectionBodyIdsLut::Terminal(ids) = entry {
+                        ids.remove(ids.iter().position(|v| *v == id).expect("present"));
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
     /// Returns the mapping between section and subsection name to section ids.
     pub(crate) fn section_ids_by_name_and_subname<'a>(
         &'a self,
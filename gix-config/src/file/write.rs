@@ -70,6 +70,119 @@ impl File<'_> {
     }
 }
 
+/// Normalized serialization
+impl File<'_> {
+    /// Serialize this type into a `BString` with normalized whitespace, see [`write_to_normalized()`][File::write_to_normalized()].
+    #[must_use]
+    pub fn to_bstring_normalized(&self) -> BString {
+        let mut buf = Vec::new();
+        self.write_to_normalized(&mut buf).expect("io error impossible");
+        buf.into()
+    }
+
+    /// Stream ourselves to the given `out` deterministically, independently of the whitespace that was parsed or
+    /// configured when adding values.
+    ///
+    /// Keys and comments within sections are indented with a single tab, `=` is surrounded by a single space and
+    /// trailing comments are separated from what precedes them by a single space as well. Whitespace at the end of
+    /// lines is removed. Empty lines, comments and the content of values, including multi-line values, are retained.
+    pub fn write_to_normalized(&self, mut out: impl std::io::Write) -> std::io::Result<()> {
+        let nl = self.detect_newline_style();
+        let mut writer = NormalizedWriter::default();
+        for event in self.frontmatter_events.as_ref() {
+            writer.write(event, false, &mut out)?;
+        }
+        for section_id in &self.section_order {
+            let section = self.sections.get(section_id).expect("known section-id");
+            writer.end_line(nl, &mut out)?;
+            section.header.write_to(&mut out)?;
+            writer.at_line_start = false;
+            for event in section.body.as_ref() {
+                writer.write(event, true, &mut out)?;
+            }
+            for event in self.frontmatter_post_section.get(section_id).into_iter().flatten() {
+                writer.write(event, false, &mut out)?;
+            }
+        }
+        writer.end_line(nl, &mut out)
+    }
+}
+
+struct NormalizedWriter {
+    at_line_start: bool,
+    in_multi_line_value: bool,
+    pending_separator: bool,
+}
+
+impl Default for NormalizedWriter {
+    fn default() -> Self {
+        NormalizedWriter {
+            at_line_start: true,
+            in_multi_line_value: false,
+            pending_separator: false,
+        }
+    }
+}
+
+impl NormalizedWriter {
+    fn write(&mut self, event: &Event<'_>, indent: bool, out: &mut impl std::io::Write) -> std::io::Result<()> {
+        match event {
+            Event::Whitespace(_) => {}
+            Event::Newline(nl) => {
+                out.write_all(nl.as_ref())?;
+                self.at_line_start = !self.in_multi_line_value;
+            }
+            Event::SectionKey(key) => {
+                if indent {
+                    out.write_all(b"\t")?;
+                }
+                out.write_all(key.0.as_ref())?;
+                self.at_line_start = false;
+            }
+            Event::KeyValueSeparator => self.pending_separator = true,
+            Event::Value(value) | Event::ValueNotDone(value) | Event::ValueDone(value) => {
+                if std::mem::take(&mut self.pending_separator) {
+                    out.write_all(if value.is_empty() && !matches!(event, Event::ValueNotDone(_)) {
+                        b" ="
+                    } else {
+                        b" = "
+                    })?;
+                }
+                out.write_all(value.as_ref())?;
+                self.in_multi_line_value = matches!(event, Event::ValueNotDone(_));
+                if self.in_multi_line_value {
+                    out.write_all(b"\\")?;
+                }
+            }
+            Event::Comment(comment) => {
+                if self.at_line_start {
+                    if indent {
+                        out.write_all(b"\t")?;
+                    }
+                } else {
+                    out.write_all(b" ")?;
+                }
+                out.write_all(&[comment.tag])?;
+                out.write_all(comment.text.trim_end())?;
+                self.at_line_start = false;
+            }
+            Event::SectionHeader(header) => {
+                header.write_to(&mut *out)?;
+                self.at_line_start = false;
+            }
+        }
+        Ok(())
+    }
+
+    fn end_line(&mut self, nl: &[u8], out: &mut impl std::io::Write) -> std::io::Result<()> {
+        if !self.at_line_start {
+            out.write_all(nl)?;
+            self.at_line_start = true;
+        }
+        Ok(())
+    }
+}
+
 pub(crate) fn ends_with_newline(e: &[crate::parse::Event<'_>], nl: impl AsRef<[u8]>, default: bool) -> bool {
     if e.is_empty() {
         return default;
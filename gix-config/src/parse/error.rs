@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use bstr::{BStr, BString};
+
 use crate::parse::Error;
 
 /// A list of parsers that parsing can fail on. This is used for pretty-printing errors
@@ -33,16 +35,30 @@ impl Error {
     pub fn remaining_data(&self) -> &[u8] {
         &self.parsed_until
     }
+
+    /// The origin of the data that failed to parse, like the path to a file, if it was provided
+    /// with [`with_origin()`][Self::with_origin()].
+    #[must_use]
+    pub fn origin(&self) -> Option<&BStr> {
+        self.origin.as_ref().map(AsRef::as_ref)
+    }
+
+    /// Associate this error with the `origin` of the data that failed to parse, like the path to a file,
+    /// to have it displayed as part of the error message.
+    #[must_use]
+    pub fn with_origin(mut self, origin: impl Into<BString>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Got an unexpected token on line {} while trying to parse a {}: ",
-            self.line_number + 1,
-            self.last_attempted_parser,
-        )?;
+        write!(f, "Got an unexpected token on line {}", self.line_number + 1)?;
+        if let Some(origin) = &self.origin {
+            write!(f, " of '{origin}'")?;
+        }
+        write!(f, " while trying to parse a {}: ", self.last_attempted_parser)?;
 
         let data_size = self.parsed_until.len();
         let data = std::str::from_utf8(&self.parsed_until);
@@ -0,0 +1,59 @@
+/// The error returned by [`from_reader()`][crate::parse::from_reader()].
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum Error {
+    #[error("Could not read configuration data")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Parse(#[from] crate::parse::Error),
+}
+
+pub(crate) mod function {
+    use std::io::BufRead;
+
+    use super::Error;
+    use crate::parse::{from_bytes, Event};
+
+    /// Parse the configuration data from `read` in chunks and pass all events to `dispatch` as they are encountered,
+    /// without ever holding all data in memory.
+    ///
+    /// Chunks end right before a line that starts a new section, so the memory used is bounded by the size of the largest section,
+    /// rather than by the size of the whole input. The events are the same as the ones produced by [`from_bytes()`][crate::parse::from_bytes()],
+    /// but they only live until the next chunk is parsed, and may have to be [converted][Event::to_owned()] to be kept.
+    ///
+    /// Parse errors have the same line numbers as if all data was parsed at once, but their
+    /// [remaining data][crate::parse::Error::remaining_data()] is limited to the chunk that failed to parse.
+    /// Note that events are dispatched while parsing, so all events before an error will have been seen already.
+    pub fn from_reader(read: impl std::io::Read, mut dispatch: impl FnMut(Event<'_>)) -> Result<(), Error> {
+        let mut read = std::io::BufReader::new(read);
+        let mut chunk = Vec::new();
+        let mut line = Vec::new();
+        let mut newlines_before_chunk = 0;
+        let mut continues_value = false;
+        loop {
+            line.clear();
+            let eof = read.read_until(b'\n', &mut line)? == 0;
+            let starts_section = !continues_value && line.iter().find(|b| !matches!(b, b' ' | b'\t')) == Some(&b'[');
+            if (eof || starts_section) && !chunk.is_empty() {
+                from_bytes(&chunk, &mut dispatch).map_err(|mut err| {
+                    err.line_number += newlines_before_chunk;
+                    err
+                })?;
+                newlines_before_chunk += chunk.iter().filter(|b| **b == b'\n').count();
+                chunk.clear();
+            }
+            if eof {
+                break;
+            }
+            // Err on the side of caution and don't split after backslashes that only escape themselves
+            // or are part of comments, which merely makes chunks larger.
+            continues_value = line
+                .iter()
+                .rev()
+                .find(|b| !matches!(b, b'\n' | b'\r'))
+                .map_or(false, |b| *b == b'\\');
+            chunk.extend_from_slice(&line);
+        }
+        Ok(())
+    }
+}
@@ -3,7 +3,8 @@
 //! explicit reason to work with events instead.
 //!
 //! The workflow for interacting with this is to use
-//! [`from_bytes()`] to obtain all parse events or tokens of the given input.
+//! [`from_bytes()`] to obtain all parse events or tokens of the given input,
+//! or [`from_reader()`] to do the same with large inputs that shouldn't be held in memory at once.
 //!
 //! On a higher level, one can use [`Events`] to parse all events into a set
 //! of easily interpretable data type, similar to what [`File`] does.
@@ -16,6 +17,9 @@ use bstr::BStr;
 
 mod nom;
 pub use self::nom::from_bytes;
+///
+pub mod from_reader;
+pub use from_reader::function::from_reader;
 mod event;
 #[path = "events.rs"]
 mod events_type;
@@ -107,10 +111,11 @@ pub struct Comment<'a> {
 
 /// A parser error reports the one-indexed line number where the parsing error
 /// occurred, as well as the last parser node and the remaining data to be
-/// parsed.
+/// parsed, along with the origin of the data if known.
 #[derive(PartialEq, Debug)]
 pub struct Error {
     line_number: usize,
     last_attempted_parser: error::ParseNode,
     parsed_until: bstr::BString,
+    origin: Option<bstr::BString>,
 }
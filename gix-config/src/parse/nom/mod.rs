@@ -57,6 +57,7 @@ pub fn from_bytes<'a>(input: &'a [u8], mut dispatch: impl FnMut(Event<'a>)) -> R
         line_number: newlines,
         last_attempted_parser: node,
         parsed_until: i.as_bstr().into(),
+        origin: None,
     })?;
 
     // This needs to happen after we collect sections, otherwise the line number
@@ -66,6 +67,7 @@ pub fn from_bytes<'a>(input: &'a [u8], mut dispatch: impl FnMut(Event<'a>)) -> R
             line_number: newlines,
             last_attempted_parser: node,
             parsed_until: i.as_bstr().into(),
+            origin: None,
         });
     }
 
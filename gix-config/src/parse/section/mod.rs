@@ -165,16 +165,16 @@ mod types {
         "Valid names consist of alphanumeric characters or dashes.",
         is_valid_name,
         bstr::BStr,
-        "Wrapper struct for section header names, like `remote`, since these are case-insensitive."
+        "Wrapper struct for section header names, like `remote`, since these are case-insensitive.\n\nValid names match `[a-zA-Z0-9-]+`, and subsections are kept separately."
     );
 
     generate_case_insensitive!(
         Key,
         key,
-        "Valid keys consist alphanumeric characters or dashes, starting with an alphabetic character.",
+        "Valid keys consist of alphanumeric characters or dashes, starting with an alphabetic character.",
         is_valid_key,
         bstr::BStr,
-        "Wrapper struct for key names, like `path` in `include.path`, since keys are case-insensitive."
+        "Wrapper struct for key names, like `path` in `include.path`, since keys are case-insensitive.\n\nJust like in git, valid keys match `[a-zA-Z][a-zA-Z0-9-]*`, so they can't be empty, start with a digit or a dash, or contain dots."
     );
 }
 pub use types::{key, name, Key, Name};
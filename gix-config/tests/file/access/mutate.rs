@@ -51,6 +51,99 @@ mod rename_section {
 
     use gix_config::{file::rename_section, parse::section};
 
+    use crate::file::cow_str;
+
+    #[test]
+    fn body_and_comments_are_retained_and_lookups_use_the_new_name() -> crate::Result {
+        let mut file = gix_config::File::try_from(
+            "[branch \"new\"]\n\tremote = first\n# before\n[branch \"old\"] # header comment\n  remote = origin ; why\n\n  merge = refs/heads/main\n[core]\n\tbare = false\n",
+        )?;
+        file.rename_section_and_merge(
+            "branch",
+            Some("old".into()),
+            "branch",
+            Some(Cow::Borrowed("new".into())),
+        )?;
+        assert_eq!(
+            file.to_string(),
+            "[branch \"new\"]\n\tremote = first\n# before\n[branch \"new\"] # header comment\n  remote = origin ; why\n\n  merge = refs/heads/main\n[core]\n\tbare = false\n"
+        );
+        assert!(
+            file.section("branch", Some("old".into())).is_err(),
+            "the old name can't be found anymore"
+        );
+        assert_eq!(
+            file.string("branch", Some("new".into()), "remote")
+                .expect("present")
+                .as_ref(),
+            "origin",
+            "the renamed section is the last one with its new name, hence it takes precedence"
+        );
+        assert_eq!(
+            file.strings("branch", Some("new".into()), "remote").expect("present"),
+            vec![cow_str("first"), cow_str("origin")],
+            "values are returned in section order"
+        );
+
+        file.rename_section("branch", Some("new".into()), "other", None)?;
+        assert_eq!(
+            file.string("other", None, "merge").expect("present").as_ref(),
+            "refs/heads/main"
+        );
+        assert_eq!(
+            file.string("branch", Some("new".into()), "remote")
+                .expect("present")
+                .as_ref(),
+            "first"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn renaming_onto_an_existing_section_fails_unless_merging() -> crate::Result {
+        let input = "[branch \"new\"]\n\tremote = first\n[branch \"old\"]\n\tremote = origin\n[core]\n\tbare = false\n";
+        let mut file = gix_config::File::try_from(input)?;
+        assert!(matches!(
+            file.rename_section("branch", Some("old".into()), "branch", Some(Cow::Borrowed("new".into()))),
+            Err(rename_section::Error::SectionExists { name, subsection_name })
+                if name == "branch" && subsection_name.as_ref().map(|n| n.as_slice()) == Some(b"new".as_slice())
+        ));
+        assert!(
+            matches!(
+                file.rename_section_filter(
+                    "branch",
+                    Some("old".into()),
+                    "Branch",
+                    Some(Cow::Borrowed("new".into())),
+                    &mut |_| true
+                ),
+                Err(rename_section::Error::SectionExists { .. })
+            ),
+            "section names are case-insensitive"
+        );
+        assert_eq!(file.to_string(), input, "nothing was changed");
+
+        file.rename_section(
+            "branch",
+            Some("old".into()),
+            "branch",
+            Some(Cow::Borrowed("old".into())),
+        )?;
+        assert_eq!(file.to_string(), input, "renaming a section onto itself is fine");
+
+        file.rename_section_and_merge(
+            "branch",
+            Some("old".into()),
+            "branch",
+            Some(Cow::Borrowed("new".into())),
+        )?;
+        assert_eq!(
+            file.strings("branch", Some("new".into()), "remote").expect("present"),
+            vec![cow_str("first"), cow_str("origin")]
+        );
+        Ok(())
+    }
+
     #[test]
     fn section_renaming_validates_new_name() {
         let mut file = gix_config::File::try_from("[core] a = b").unwrap();
@@ -96,3 +189,94 @@ mod set_meta {
         Ok(())
     }
 }
+
+mod merge {
+    use std::convert::TryFrom;
+
+    use gix_config::{file::merge::Precedence, File};
+
+    use crate::file::cow_str;
+
+    fn ours() -> File<'static> {
+        File::try_from("[core]\n\ta = ours\n\tm = one\n\tm = two\n[remote \"origin\"]\n\turl = ours\n").unwrap()
+    }
+
+    fn theirs() -> File<'static> {
+        File::try_from(
+            "[core]\n\ta = theirs\n\tb = theirs\n\tm = three\n\timplicit\n[Core]\n\tm = four\n[remote \"upstream\"]\n\turl = theirs\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn overwrite_replaces_all_values_of_keys_in_other() -> crate::Result {
+        let mut config = ours();
+        config.merge(&theirs(), Precedence::Overwrite);
+        assert_eq!(config.string("core", None, "a").expect("present"), cow_str("theirs"));
+        assert_eq!(config.string("core", None, "b").expect("present"), cow_str("theirs"));
+        assert_eq!(
+            config.strings("core", None, "m").expect("present"),
+            vec![cow_str("three"), cow_str("four")],
+            "multi-values are replaced by all values of the other file, across sections"
+        );
+        assert!(
+            config.boolean("core", None, "implicit").expect("present")?,
+            "implicit values stay implicit"
+        );
+        assert_eq!(
+            config.string("remote", Some("origin".into()), "url").expect("present"),
+            cow_str("ours")
+        );
+        assert_eq!(
+            config
+                .string("remote", Some("upstream".into()), "url")
+                .expect("present"),
+            cow_str("theirs"),
+            "missing sections are created"
+        );
+        assert_eq!(
+            config.to_string(),
+            "[core]\n\ta = theirs\n\tb = theirs\n\tm = three\n\tm = four\n\timplicit\n[remote \"origin\"]\n\turl = ours\n[remote \"upstream\"]\n\turl = theirs\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fill_missing_keeps_existing_keys() -> crate::Result {
+        let mut config = ours();
+        config.merge(&theirs(), Precedence::FillMissing);
+        assert_eq!(config.string("core", None, "a").expect("present"), cow_str("ours"));
+        assert_eq!(config.string("core", None, "b").expect("present"), cow_str("theirs"));
+        assert_eq!(
+            config.strings("core", None, "m").expect("present"),
+            vec![cow_str("one"), cow_str("two")],
+            "existing multi-values are left untouched"
+        );
+        assert!(config.boolean("core", None, "implicit").expect("present")?);
+        assert_eq!(
+            config
+                .string("remote", Some("upstream".into()), "url")
+                .expect("present"),
+            cow_str("theirs")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn additive_accumulates_values() -> crate::Result {
+        let mut config = ours();
+        config.merge(&theirs(), Precedence::Additive);
+        assert_eq!(
+            config.string("core", None, "a").expect("present"),
+            cow_str("theirs"),
+            "the last value wins for single values"
+        );
+        assert_eq!(config.strings("core", None, "a").expect("present").len(), 2);
+        assert_eq!(
+            config.strings("core", None, "m").expect("present"),
+            vec![cow_str("one"), cow_str("two"), cow_str("three"), cow_str("four")]
+        );
+        assert_eq!(config.sections().count(), 3, "values are added to existing sections");
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use std::{borrow::Cow, convert::TryFrom};
+use std::{
+    borrow::Cow,
+    convert::{TryFrom, TryInto},
+};
 
 use bstr::BStr;
 use gix_config::{
@@ -220,6 +223,32 @@ fn value_names_are_case_insensitive() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn section_and_key_names_are_case_insensitive_but_subsections_are_not() -> crate::Result {
+    let input = "[Core]\n\tautoCRLF = input\n[remote \"Origin\"]\n\turl = a\n";
+    let mut file = File::try_from(input)?;
+    assert_eq!(
+        file.string("core", None, "autocrlf").expect("present").as_ref(),
+        "input"
+    );
+    assert_eq!(file.string_by_key("CORE.AutoCrlf").expect("present").as_ref(), "input");
+    assert!(file.section_mut("CORE", None)?.contains_key("AUTOcrlf"));
+    assert!(file.section("REMOTE", Some("Origin".into())).is_ok());
+    assert!(
+        file.section("remote", Some("origin".into())).is_err(),
+        "subsection names are case-sensitive"
+    );
+    assert_eq!(file.string("remote", Some("origin".into()), "url"), None);
+
+    file.section_mut("core", None)?.set("AutoCrlf".try_into()?, "false");
+    assert_eq!(
+        file.to_bstring(),
+        "[Core]\n\tautoCRLF = false\n[remote \"Origin\"]\n\turl = a\n",
+        "the original casing is retained when serializing"
+    );
+    Ok(())
+}
+
 #[test]
 fn single_section() {
     let config = File::try_from("[core]\na=b\nc").unwrap();
@@ -256,6 +285,42 @@ fn sections_by_name() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn has_section_and_section_count() -> crate::Result {
+    let config = File::try_from(
+        r#"[core]
+    a = b
+[Remote "origin"]
+    url = a
+[remote "upstream"]
+    url = b
+[remote "origin"]
+    fetch = c"#,
+    )?;
+    assert!(!config.has_section("remote", None), "no section without subsection");
+    assert!(config.has_section("remote", Some("origin".into())));
+    assert!(
+        config.has_section("REMOTE", Some("upstream".into())),
+        "section names are case-insensitive"
+    );
+    assert!(
+        !config.has_section("remote", Some("Origin".into())),
+        "subsection names are case-sensitive"
+    );
+    assert!(config.has_section("core", None));
+    assert!(!config.has_section("core", Some("sub".into())));
+    assert!(!config.has_section("unknown", None));
+
+    assert_eq!(config.section_count("unknown"), 0);
+    assert_eq!(config.section_count("core"), 1);
+    assert_eq!(
+        config.section_count("remote"),
+        3,
+        "all subsections are counted, including duplicates"
+    );
+    Ok(())
+}
+
 #[test]
 fn unknown_section() -> crate::Result {
     let config = File::default();
@@ -398,3 +463,164 @@ fn overrides_with_implicit_booleans_work_across_sections() {
         "empty implicit booleans "
     );
 }
+
+#[test]
+fn boolean_spellings_and_integer_suffixes_are_understood() -> crate::Result {
+    let config = r#"
+        [bool]
+            t = true
+            y = yes
+            o = ON
+            one = 1
+            f = off
+        [int]
+            kilo = 1k
+            mega = 1M
+            giga = 2g
+            multi = 1
+            multi = 3k
+        "#;
+    let config = File::try_from(config)?;
+    for key in ["t", "y", "o", "one"] {
+        assert_eq!(config.boolean("bool", None, key), Some(Ok(true)), "{key}");
+    }
+    assert_eq!(config.boolean("bool", None, "f"), Some(Ok(false)));
+    assert_eq!(config.boolean("bool", None, "missing"), None);
+
+    assert_eq!(config.integer("int", None, "kilo"), Some(Ok(1024)));
+    assert_eq!(config.integer("int", None, "mega"), Some(Ok(1024 * 1024)));
+    assert_eq!(config.integer_by_key("int.giga"), Some(Ok(2 * 1024 * 1024 * 1024)));
+    assert_eq!(
+        config.integer("int", None, "multi"),
+        Some(Ok(3 * 1024)),
+        "the last of multiple values wins"
+    );
+    assert!(
+        config.integer("bool", None, "t").expect("present").is_err(),
+        "values that can't be converted are errors"
+    );
+    Ok(())
+}
+
+#[test]
+fn quoted_and_escaped_values_are_unescaped() -> crate::Result {
+    let config = File::try_from(
+        r#"[a]
+    path = "C:\\Users\\me"
+    quotes = say \"hi\" "to ; you"
+    escapes = a\nb\tc
+    continued = a \
+b"#,
+    )?;
+    assert_eq!(
+        config.string("a", None, "path").expect("present").as_ref(),
+        r"C:\Users\me"
+    );
+    assert_eq!(
+        config.string("a", None, "quotes").expect("present").as_ref(),
+        r#"say "hi" to ; you"#
+    );
+    assert_eq!(
+        config.string("a", None, "escapes").expect("present").as_ref(),
+        "a\nb\tc"
+    );
+    assert_eq!(config.string("a", None, "continued").expect("present").as_ref(), "a b");
+    Ok(())
+}
+
+#[test]
+fn colors_are_parsed_like_git() -> crate::Result {
+    let config = File::try_from(
+        r##"[color "diff"]
+    meta = bold red
+    old = brightgreen black
+    new = "#ff0000"
+    commit = reverse
+    frag = not-a-color"##,
+    )?;
+    assert_eq!(
+        config.color("color", Some("diff".into()), "meta").expect("present")?,
+        Color {
+            foreground: Some(color::Name::Red),
+            background: None,
+            attributes: color::Attribute::BOLD
+        }
+    );
+    assert_eq!(
+        config.color_by_key("color.diff.old").expect("present")?,
+        Color {
+            foreground: Some(color::Name::BrightGreen),
+            background: Some(color::Name::Black),
+            attributes: color::Attribute::empty()
+        }
+    );
+    assert_eq!(
+        config.color_by_key("color.diff.new").expect("present")?,
+        Color {
+            foreground: Some(color::Name::Rgb(255, 0, 0)),
+            background: None,
+            attributes: color::Attribute::empty()
+        }
+    );
+    assert_eq!(
+        config.color_by_key("color.diff.commit").expect("present")?,
+        Color {
+            foreground: None,
+            background: None,
+            attributes: color::Attribute::REVERSE
+        }
+    );
+    assert!(
+        config.color_by_key("color.diff.frag").expect("present").is_err(),
+        "invalid colors are errors"
+    );
+    assert!(config.color_by_key("color.diff.whitespace").is_none());
+    Ok(())
+}
+
+#[test]
+fn section_events_contain_everything_in_order_of_occurrence() -> crate::Result {
+    use gix_config::parse::{section, Event};
+    let input = "[a]\n  k = v # c\n\timplicit\n  m = one\\\n  two\n";
+    let config = File::try_from(input)?;
+    let section = config.section("a", None)?;
+    assert_eq!(
+        section.events(),
+        &[
+            Event::Newline(cow_str("\n")),
+            Event::Whitespace(cow_str("  ")),
+            Event::SectionKey(section::Key::try_from("k")?),
+            Event::Whitespace(cow_str(" ")),
+            Event::KeyValueSeparator,
+            Event::Whitespace(cow_str(" ")),
+            Event::Value(cow_str("v")),
+            Event::Whitespace(cow_str(" ")),
+            Event::Comment(gix_config::parse::Comment {
+                tag: b'#',
+                text: cow_str(" c")
+            }),
+            Event::Newline(cow_str("\n")),
+            Event::Whitespace(cow_str("\t")),
+            Event::SectionKey(section::Key::try_from("implicit")?),
+            Event::Value(cow_str("")),
+            Event::Newline(cow_str("\n")),
+            Event::Whitespace(cow_str("  ")),
+            Event::SectionKey(section::Key::try_from("m")?),
+            Event::Whitespace(cow_str(" ")),
+            Event::KeyValueSeparator,
+            Event::Whitespace(cow_str(" ")),
+            Event::ValueNotDone(cow_str("one")),
+            Event::Newline(cow_str("\n")),
+            Event::ValueDone(cow_str("  two")),
+            Event::Newline(cow_str("\n")),
+        ][..]
+    );
+
+    let mut out = Vec::new();
+    section.header().write_to(&mut out)?;
+    for event in section.events() {
+        event.write_to(&mut out)?;
+    }
+    assert_eq!(out, input.as_bytes(), "writing all events reproduces the section");
+    Ok(())
+}
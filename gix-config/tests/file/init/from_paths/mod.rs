@@ -33,6 +33,22 @@ mod from_path_no_includes {
         assert_eq!(config.raw_value("core", None, "boolean").unwrap().as_ref(), "true");
         assert_eq!(config.num_values(), 1);
     }
+
+    #[test]
+    fn parse_errors_mention_the_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config");
+        std::fs::write(config_path.as_path(), b"[core]\n4a = true").unwrap();
+
+        let err = gix_config::File::from_path_no_includes(config_path.clone(), gix_config::Source::Local).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Got an unexpected token on line 2 of '{}' while trying to parse a name: '4a = true'",
+                config_path.display()
+            )
+        );
+    }
 }
 
 #[test]
@@ -132,6 +148,51 @@ fn frontmatter_is_maintained_in_multiple_files() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn files_ordered_by_source_resolve_with_git_precedence() -> crate::Result {
+    let dir = tempdir()?;
+    let system = dir.path().join("system");
+    fs::write(&system, b"[remote \"origin\"]\nfetch = system\n[core]\nbare = true")?;
+    let global = dir.path().join("global");
+    fs::write(
+        &global,
+        b"[core]\nbare = true\nfsmonitor = true\n[remote \"origin\"]\nfetch = global",
+    )?;
+    let local = dir.path().join("local");
+    fs::write(&local, b"[core]\nbare = false\n[remote \"origin\"]\nfetch = local")?;
+
+    let mut metas = vec![
+        gix_config::file::Metadata::try_from_path(&local, Source::Local)?,
+        gix_config::file::Metadata::try_from_path(&system, Source::System)?,
+        gix_config::file::Metadata::try_from_path(&global, Source::User)?,
+    ];
+    metas.sort_by_key(|meta| meta.source);
+    let config = File::from_paths_metadata(metas, Default::default())?.expect("non-empty");
+
+    assert_eq!(
+        config.boolean("core", None, "bare"),
+        Some(Ok(false)),
+        "the local file overrides the global one"
+    );
+    assert_eq!(
+        config.boolean("core", None, "fsmonitor"),
+        Some(Ok(true)),
+        "values not overridden are visible"
+    );
+    assert_eq!(
+        config.strings("remote", Some("origin".into()), "fetch"),
+        Some(vec![cow_str("system"), cow_str("global"), cow_str("local")]),
+        "multi-values accumulate in order of ascending precedence"
+    );
+    assert_eq!(
+        config.string_filter("core", None, "bare", &mut |meta| meta.source.kind()
+            != gix_config::source::Kind::Repository),
+        Some(cow_str("true")),
+        "filters can ignore sources with higher precedence"
+    );
+    Ok(())
+}
+
 #[test]
 fn multiple_paths_multi_value_and_filter() -> crate::Result {
     let dir = tempdir()?;
@@ -37,6 +37,47 @@ fn section_mut_by_id() {
     assert_eq!(section.header().subsection_name(), None);
 }
 
+#[test]
+fn iter_yields_all_key_value_pairs_in_order() -> crate::Result {
+    let mut config: gix_config::File<'_> = r#"
+        [remote "origin"]
+            url = https://example.com/repo
+            fetch = +refs/heads/*:refs/remotes/origin/*
+            FETCH = +refs/tags/*:refs/tags/*
+            fetch = +refs/notes/*:\
+refs/notes/*
+            mirror
+            pushurl = "quoted \\ value"
+        "#
+    .parse()?;
+    let expected = [
+        ("url", "https://example.com/repo"),
+        ("fetch", "+refs/heads/*:refs/remotes/origin/*"),
+        ("FETCH", "+refs/tags/*:refs/tags/*"),
+        ("fetch", "+refs/notes/*:refs/notes/*"),
+        ("mirror", ""),
+        ("pushurl", "quoted \\ value"),
+    ];
+
+    let section = config.section("remote", Some("origin".into()))?;
+    let actual: Vec<_> = section.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    assert_eq!(
+        actual,
+        expected.map(|(k, v)| (k.to_string(), v.to_string())),
+        "keys are yielded once per value as written, with normalized values"
+    );
+    assert_eq!(section.iter().count(), section.num_values());
+
+    let mut section = config.section_mut("remote", Some("origin".into()))?;
+    section.push("url".try_into()?, Some("other".into()));
+    assert_eq!(
+        section.iter().last().map(|(k, v)| (k.to_string(), v.into_owned())),
+        Some(("url".into(), "other".into())),
+        "mutable sections can be iterated as well"
+    );
+    Ok(())
+}
+
 mod remove {
     use super::multi_value_section;
 
@@ -63,6 +104,60 @@ mod remove {
     }
 }
 
+mod remove_value {
+    use std::convert::TryInto;
+
+    use crate::file::cow_str;
+
+    #[test]
+    fn only_matching_values_of_a_multi_valued_key_are_removed() -> crate::Result {
+        let mut config: gix_config::File = r#"[remote "origin"]
+	url = https://example.com/repo
+	fetch = +refs/heads/*:refs/remotes/origin/*
+	fetch = +refs/tags/*:refs/tags/*
+	fetch = +refs/notes/*:refs/notes/*
+	pushurl = https://example.com/push
+"#
+        .parse()?;
+        let mut section = config.section_mut("remote", Some("origin".into()))?;
+        let removed = section.remove_value("fetch", |value| value == "+refs/tags/*:refs/tags/*");
+        assert_eq!(removed, vec![cow_str("+refs/tags/*:refs/tags/*")]);
+        assert_eq!(
+            section.values("fetch"),
+            vec![
+                cow_str("+refs/heads/*:refs/remotes/origin/*"),
+                cow_str("+refs/notes/*:refs/notes/*")
+            ]
+        );
+        assert!(
+            section.remove_value("fetch", |_| false).is_empty(),
+            "nothing is removed if nothing matches"
+        );
+        assert!(section.remove_value("missing", |_| true).is_empty());
+        assert_eq!(
+            config.to_string(),
+            r#"[remote "origin"]
+	url = https://example.com/repo
+	fetch = +refs/heads/*:refs/remotes/origin/*
+	fetch = +refs/notes/*:refs/notes/*
+	pushurl = https://example.com/push
+"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn all_values_can_be_removed() -> crate::Result {
+        let mut config = super::multi_value_section();
+        let mut section = config.section_mut("a", None)?;
+        section.push("a".try_into()?, Some("w".into()));
+        assert_eq!(section.remove_value("a", |_| true), vec![cow_str("v"), cow_str("w")]);
+        assert!(!section.contains_key("a"));
+        assert_eq!(section.num_values(), 4);
+        Ok(())
+    }
+}
+
 mod pop {
     use super::multi_value_section;
 
@@ -120,6 +215,47 @@ mod set {
     }
 }
 
+mod set_single {
+    use std::convert::TryInto;
+
+    use super::multi_value_section;
+    use crate::file::cow_str;
+
+    #[test]
+    fn replaces_all_values_of_a_key_with_one() -> crate::Result {
+        let mut config: gix_config::File<'_> = "[a]\n\tkey = one\n\tother = x\n\tKEY = two \\\n  three\n".parse()?;
+        let mut section = config.section_mut("a", None)?;
+        let previous = section.set_single("key".try_into()?, "new");
+        assert_eq!(previous, vec![cow_str("one"), cow_str("two   three")]);
+        assert_eq!(section.values("key"), vec![cow_str("new")]);
+        assert_eq!(
+            config.to_bstring(),
+            "[a]\n\tkey = new\n\tother = x\n",
+            "the first value is updated in place and the others are removed with their line"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn implicit_values_gain_a_separator() -> crate::Result {
+        let mut config = multi_value_section();
+        let mut section = config.section_mut("a", None)?;
+        assert_eq!(section.set_single("d".try_into()?, "v"), vec![cow_str("")]);
+        assert_eq!(section.value("d").expect("present").as_ref(), "v");
+        assert_eq!(section.values("d").len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_keys_are_added() -> crate::Result {
+        let mut config = multi_value_section();
+        let mut section = config.section_mut("a", None)?;
+        assert!(section.set_single("new".try_into()?, "value").is_empty());
+        assert_eq!(section.value("new").expect("present").as_ref(), "value");
+        Ok(())
+    }
+}
+
 mod push {
     use std::convert::{TryFrom, TryInto};
 
@@ -200,11 +336,51 @@ mod push {
             assert_eq!(config.to_bstring(), expected);
         }
     }
+
+    #[test]
+    fn escaped_values_are_unescaped_when_read_back() -> crate::Result {
+        for value in [
+            r#"C:\Users\me"#,
+            r#"say "hi""#,
+            "a\nb\tc",
+            " leading and trailing ",
+            "key = # not a comment",
+        ] {
+            let mut config = gix_config::File::default();
+            config
+                .new_section("a", None)?
+                .push(Key::try_from("k")?, Some(value.into()));
+            let config: gix_config::File = config.to_bstring().to_string().parse()?;
+            assert_eq!(
+                config.string("a", None, "k").expect("present").as_ref(),
+                value,
+                "escaping when pushing is reversed when reading"
+            );
+        }
+        Ok(())
+    }
 }
 
 mod push_with_comment {
+    use std::convert::TryInto;
+
     use gix_config::parse::section::Key;
 
+    #[test]
+    fn existing_comments_survive_edits_of_other_values() -> crate::Result {
+        let mut config: gix_config::File =
+            "# file comment\n[a] ; header comment\n\t# about b\n\tb = 1 # trailing b\n\tc = 2 ; trailing c\n"
+                .parse()?;
+        let mut section = config.section_mut("a", None)?;
+        section.set("c".try_into()?, "20");
+        section.push_with_comment("d".try_into()?, Some("3".into()), "added");
+        assert_eq!(
+            config.to_string(),
+            "# file comment\n[a] ; header comment\n\t# about b\n\tb = 1 # trailing b\n\tc = 20 ; trailing c\n\td = 3 # added\n"
+        );
+        Ok(())
+    }
+
     #[test]
     fn various_comments_and_escaping() {
         for (comment, expected) in [
@@ -254,6 +430,39 @@ mod set_leading_whitespace {
         Ok(())
     }
 
+    #[test]
+    fn two_spaces_are_used_for_subsequent_pushes() -> crate::Result {
+        let mut config = gix_config::File::default();
+        let mut section = config.new_section("core", None)?;
+        section.try_set_leading_whitespace(cow_str("  ").into())?;
+        assert_eq!(section.leading_whitespace(), Some("  ".into()));
+        section.push(Key::try_from("a")?, Some("v".into()));
+        section.push(Key::try_from("b")?, None);
+
+        let nl = section.newline().to_owned();
+        assert_eq!(config.to_string(), format!("[core]{nl}  a = v{nl}  b{nl}"));
+        Ok(())
+    }
+
+    #[test]
+    fn non_whitespace_is_an_error_with_try() -> crate::Result {
+        let mut config = gix_config::File::default();
+        let mut section = config.new_section("core", None)?;
+        section.try_set_leading_whitespace(cow_str(" ").into())?;
+        assert!(matches!(
+            section.try_set_leading_whitespace(cow_str(" x ").into()),
+            Err(gix_config::file::set_leading_whitespace::Error::NonWhitespace { whitespace }) if whitespace == " x "
+        ));
+        assert_eq!(
+            section.leading_whitespace(),
+            Some(" ".into()),
+            "the previous value is retained"
+        );
+        section.try_set_leading_whitespace(None)?;
+        assert_eq!(section.leading_whitespace(), None);
+        Ok(())
+    }
+
     #[test]
     #[should_panic]
     fn panics_if_non_whitespace_is_used() {
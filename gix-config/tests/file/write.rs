@@ -1,4 +1,7 @@
-use std::convert::TryFrom;
+use std::{
+    borrow::Cow,
+    convert::{TryFrom, TryInto},
+};
 
 use bstr::ByteVec;
 use gix_config::file::{init, Metadata};
@@ -109,6 +112,57 @@ fn complex_lossless_roundtrip() {
     );
 }
 
+#[test]
+fn crlf_line_endings_roundtrip_and_are_used_for_edits() -> crate::Result {
+    let input = "[a]\r\n\tb = c\r\n";
+    let mut config = gix_config::File::try_from(input)?;
+    assert_eq!(config.to_bstring(), input, "parsed files are reproduced byte by byte");
+    assert_eq!(config.detect_newline_style(), "\r\n");
+
+    config.section_mut("a", None)?.push("d".try_into()?, Some("e".into()));
+    config.new_section("f", None)?;
+    assert_eq!(
+        config.to_bstring(),
+        "[a]\r\n\tb = c\r\n\td = e\r\n[f]\r\n",
+        "edits use the newline style of the file"
+    );
+    Ok(())
+}
+
+#[test]
+fn subsections_with_special_characters_are_escaped_and_roundtrip() -> crate::Result {
+    let subsections = ["gitdir:/path/.git", r#"say "hi""#, r"C:\path\", "sub.with.dots"];
+    let mut config = gix_config::File::new(Metadata::api());
+    for subsection in subsections {
+        config.new_section("includeIf", Some(Cow::Borrowed(subsection.into())))?;
+    }
+    let nl = config.detect_newline_style().to_string();
+    let expected = [
+        r#"[includeIf "gitdir:/path/.git"]"#,
+        r#"[includeIf "say \"hi\""]"#,
+        r#"[includeIf "C:\\path\\"]"#,
+        r#"[includeIf "sub.with.dots"]"#,
+    ]
+    .map(|header| header.to_owned() + &nl)
+    .concat();
+    assert_eq!(config.to_bstring(), expected, "quotes and backslashes are escaped");
+
+    let reparsed = gix_config::File::try_from(expected.as_str())?;
+    assert_eq!(reparsed.to_bstring(), expected, "parsed headers are reproduced exactly");
+    assert_eq!(
+        reparsed
+            .sections()
+            .map(|s| s.header().subsection_name().expect("present").to_string())
+            .collect::<Vec<_>>(),
+        subsections,
+        "escapes are folded when parsing"
+    );
+    for subsection in subsections {
+        assert!(reparsed.has_section("includeIf", Some(subsection.into())));
+    }
+    Ok(())
+}
+
 mod to_filter {
     use bstr::ByteSlice;
     use gix_config::file::Metadata;
@@ -144,3 +198,52 @@ mod to_filter {
         Ok(())
     }
 }
+
+mod to_bstring_normalized {
+    use std::borrow::Cow;
+
+    use gix_config::file::Metadata;
+
+    use crate::file::cow_str;
+
+    #[test]
+    fn whitespace_is_normalized_but_comments_and_values_are_retained() -> crate::Result {
+        let input = "; head  \n  [a]   ; side a \n    b   =   c  \n  d=e#note \n\n\timplicit\t\n  empty =\n  multi = one \\\n    two  \n      ; own line\n[b \"sub\"]\n";
+        let config: gix_config::File = input.parse()?;
+        assert_eq!(
+            config.to_bstring_normalized(),
+            "; head\n[a] ; side a\n\tb = c\n\td = e #note\n\n\timplicit\n\tempty =\n\tmulti = one \\\n    two\n\t; own line\n[b \"sub\"]\n"
+        );
+        assert_eq!(config.to_bstring(), input, "the faithful serialization isn't affected");
+        Ok(())
+    }
+
+    #[test]
+    fn pushed_and_parsed_values_serialize_the_same() -> crate::Result {
+        let parsed: gix_config::File =
+            "[core]\n    bare=false\n  path =  a b   # two words\n[remote \"origin\"]\n fetch = x\n".parse()?;
+
+        let mut built = gix_config::File::new(Metadata::api());
+        {
+            let mut section = built.new_section("core", None)?;
+            section.set_leading_whitespace(Some(Cow::Borrowed("  ".into())));
+            section.push("bare".try_into()?, Some("false".into()));
+            section.push_with_comment("path".try_into()?, Some("a b".into()), "two words");
+        }
+        built
+            .new_section("remote", cow_str("origin"))?
+            .push("fetch".try_into()?, Some("x".into()));
+
+        assert_ne!(parsed.to_bstring(), built.to_bstring());
+        let nl = built.detect_newline_style().to_string();
+        assert_eq!(
+            built.to_bstring_normalized(),
+            "[core]\n\tbare = false\n\tpath = a b # two words\n[remote \"origin\"]\n\tfetch = x\n".replace('\n', &nl)
+        );
+        assert_eq!(
+            parsed.to_bstring_normalized(),
+            "[core]\n\tbare = false\n\tpath = a b # two words\n[remote \"origin\"]\n\tfetch = x\n"
+        );
+        Ok(())
+    }
+}
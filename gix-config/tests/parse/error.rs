@@ -52,3 +52,20 @@ fn to_string() {
         "Got an unexpected token on line 1 while trying to parse a section header: '[core'"
     );
 }
+
+#[test]
+fn to_string_with_origin() {
+    let err = gix_config::File::from_bytes_with_origin(b"[core]\n 4a=3", "/path/to/config").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Got an unexpected token on line 2 of '/path/to/config' while trying to parse a name: '4a=3'"
+    );
+    match err {
+        gix_config::file::init::Error::Parse(err) => {
+            assert_eq!(err.origin().expect("set"), "/path/to/config");
+            assert_eq!(err.line_number(), 2);
+        }
+        _ => unreachable!("only parse errors can occur"),
+    }
+    assert_eq!(Events::from_str("[core").unwrap_err().origin(), None);
+}
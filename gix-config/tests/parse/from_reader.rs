@@ -0,0 +1,63 @@
+use gix_config::parse::{from_reader, Event};
+
+fn events_from_reader(input: &str) -> Result<Vec<Event<'static>>, from_reader::Error> {
+    let mut events = Vec::new();
+    from_reader(input.as_bytes(), |event| events.push(event.to_owned()))?;
+    Ok(events)
+}
+
+fn events_from_bytes(input: &str) -> Result<Vec<Event<'static>>, gix_config::parse::Error> {
+    let mut events = Vec::new();
+    gix_config::parse::from_bytes(input.as_bytes(), |event| events.push(event.to_owned()))?;
+    Ok(events)
+}
+
+#[test]
+fn events_are_seen_in_order_and_match_the_ones_of_from_bytes() -> crate::Result {
+    let input = "# front matter\n\n[core]\n\ta = b\n  [remote \"origin\"]\n\turl = value that continues \\\n[on the next line]\n\n[push] ; comment\n\tdefault\n";
+    let events = events_from_reader(input)?;
+    assert_eq!(events, events_from_bytes(input)?);
+
+    let headers: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            Event::SectionHeader(header) => Some(header.to_bstring()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        headers,
+        ["[core]", "[remote \"origin\"]", "[push]"],
+        "lines looking like headers within multi-line values aren't mistaken for such"
+    );
+    let mut out = Vec::new();
+    for event in &events {
+        event.write_to(&mut out)?;
+    }
+    assert_eq!(out, input.as_bytes(), "all input is represented");
+    Ok(())
+}
+
+#[test]
+fn errors_have_the_same_line_numbers_as_if_all_data_was_parsed_at_once() {
+    for input in [
+        "[core] a=b\\\n cd\n[core]\n\n 4a=3",
+        "[core] a=b\\\n cd\n 4a=3",
+        "[a]\n[b]\n[c]\n\n[d_e]\nf",
+        "[core",
+    ] {
+        let err = events_from_reader(input).unwrap_err();
+        let expected = events_from_bytes(input).unwrap_err();
+        match err {
+            from_reader::Error::Parse(err) => {
+                assert_eq!(err.line_number(), expected.line_number(), "{input:?}");
+                assert_eq!(
+                    err.remaining_data(),
+                    expected.remaining_data(),
+                    "the failing chunk is the last one here"
+                );
+            }
+            from_reader::Error::Io(err) => unreachable!("reading from memory doesn't fail: {err}"),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use gix_config::parse::{Event, Events, Section};
 
 mod error;
 mod from_bytes;
+mod from_reader;
 mod key;
 mod section;
 
@@ -105,6 +105,16 @@ mod key {
         assert!(Key::try_from("a.2").is_err());
         assert!(Key::try_from("##").is_err());
         assert!(Key::try_from("\"").is_err());
+        assert!(Key::try_from("-a").is_err(), "dashes can't be first");
+        assert!(Key::try_from("a b").is_err());
+        assert!(Key::try_from("ä").is_err(), "only ASCII is allowed");
+    }
+
+    #[test]
+    fn accepts_valid_format() {
+        for valid in ["a", "A", "abc-def", "a1", "a-", "a--1"] {
+            assert_eq!(key(valid).as_ref(), valid);
+        }
     }
 
     #[test]
@@ -1,18 +1,31 @@
 use bstr::{BString, ByteSlice};
 
-use crate::{pattern, pattern::Mode};
+use crate::{
+    pattern,
+    pattern::{Mode, Options, SkipReason},
+};
 
 #[inline]
 /// A sloppy parser that performs only the most basic checks, providing additional information
 /// using `pattern::Mode` flags.
 ///
 /// Returns `(pattern, mode, no_wildcard_len)`
-pub fn pattern(mut pat: &[u8]) -> Option<(BString, pattern::Mode, Option<usize>)> {
+pub fn pattern(pat: &[u8]) -> Option<(BString, pattern::Mode, Option<usize>)> {
+    pattern_with_reason(pat).ok()
+}
+
+/// Like [`pattern()`], but returns the reason for skipping `pat` if it isn't a pattern.
+pub fn pattern_with_reason(pat: &[u8]) -> Result<(BString, pattern::Mode, Option<usize>), SkipReason> {
+    pattern_with_options(pat, Options::default())
+}
+
+/// Like [`pattern_with_reason()`], but configure parsing with `options`.
+pub fn pattern_with_options(
+    mut pat: &[u8],
+    Options { allow_negation }: Options,
+) -> Result<(BString, pattern::Mode, Option<usize>), SkipReason> {
     let mut mode = Mode::empty();
-    if pat.is_empty() {
-        return None;
-    };
-    if pat.first() == Some(&b'!') {
+    if allow_negation && pat.first() == Some(&b'!') {
         mode |= Mode::NEGATIVE;
         pat = &pat[1..];
     } else if pat.first() == Some(&b'\\') {
@@ -21,8 +34,11 @@ pub fn pattern(mut pat: &[u8]) -> Option<(BString, pattern::Mode, Option<usize>)
             pat = &pat[1..];
         }
     }
+    if pat.is_empty() {
+        return Err(SkipReason::Empty);
+    }
     if pat.iter().all(|b| b.is_ascii_whitespace()) {
-        return None;
+        return Err(SkipReason::AllWhitespace);
     }
     if pat.first() == Some(&b'/') {
         mode |= Mode::ABSOLUTE;
@@ -33,6 +49,9 @@ pub fn pattern(mut pat: &[u8]) -> Option<(BString, pattern::Mode, Option<usize>)
         mode |= Mode::MUST_BE_DIR;
         pat.pop();
     }
+    if pat.is_empty() {
+        return Err(SkipReason::Empty);
+    }
 
     if !pat.contains(&b'/') {
         mode |= Mode::NO_SUB_DIR;
@@ -42,7 +61,7 @@ pub fn pattern(mut pat: &[u8]) -> Option<(BString, pattern::Mode, Option<usize>)
     }
 
     let pos_of_first_wildcard = first_wildcard_pos(&pat);
-    Some((pat, mode, pos_of_first_wildcard))
+    Ok((pat, mode, pos_of_first_wildcard))
 }
 
 fn first_wildcard_pos(pat: &[u8]) -> Option<usize> {
@@ -38,6 +38,47 @@ pub enum Case {
     Fold,
 }
 
+/// The outcome of matching a path against a [`Pattern`] with [`Pattern::match_outcome()`],
+/// with negation and directory rules already applied.
+#[derive(Debug, PartialOrd, PartialEq, Copy, Clone, Hash, Ord, Eq)]
+pub enum Match {
+    /// The pattern didn't match the path, so it has no say about it.
+    None,
+    /// A non-negated pattern matched, so the path is part of the set described by the pattern, e.g. it's ignored.
+    Include,
+    /// A negated pattern like `!foo` matched, so the path is explicitly removed from the set described by the pattern,
+    /// e.g. it's not ignored even though an earlier pattern matched it.
+    Exclude,
+}
+
+/// The reason for a pattern to be rejected when parsing, as returned by [`Pattern::from_bytes_with_reason()`].
+///
+/// Note that comments aren't known at this level, as it's up to the caller to decide if `#` starts a comment.
+#[derive(Debug, PartialOrd, PartialEq, Copy, Clone, Hash, Ord, Eq)]
+pub enum SkipReason {
+    /// The pattern was empty, possibly after removing a leading `!`, or nothing but slashes were left after removing
+    /// the leading and trailing one, like in `/` or `//`.
+    Empty,
+    /// The pattern consisted of whitespace only.
+    AllWhitespace,
+}
+
+/// Options to configure how a [`Pattern`] is parsed with [`Pattern::from_bytes_with_options()`].
+#[derive(Debug, PartialOrd, PartialEq, Copy, Clone, Hash, Ord, Eq)]
+pub struct Options {
+    /// If `true`, the default, a leading `!` negates the pattern and is removed from it.
+    ///
+    /// Otherwise, the `!` is taken literally and kept as part of the pattern text, as needed in contexts where negation
+    /// isn't supported.
+    pub allow_negation: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options { allow_negation: true }
+    }
+}
+
 impl Default for Case {
     fn default() -> Self {
         Case::Sensitive
@@ -54,24 +95,101 @@ impl Pattern {
         })
     }
 
+    /// Like [`from_bytes()`][Self::from_bytes()], but return the reason for `text` not being a pattern instead of `None`.
+    pub fn from_bytes_with_reason(text: &[u8]) -> Result<Self, SkipReason> {
+        crate::parse::pattern_with_reason(text).map(|(text, mode, first_wildcard_pos)| Pattern {
+            text,
+            mode,
+            first_wildcard_pos,
+        })
+    }
+
+    /// Like [`from_bytes_with_reason()`][Self::from_bytes_with_reason()], but configure parsing with `options`.
+    pub fn from_bytes_with_options(text: &[u8], options: pattern::Options) -> Result<Self, SkipReason> {
+        crate::parse::pattern_with_options(text, options).map(|(text, mode, first_wildcard_pos)| Pattern {
+            text,
+            mode,
+            first_wildcard_pos,
+        })
+    }
+
     /// Return true if a match is negated.
+    ///
+    /// Note that [`matches_repo_relative_path()`][Self::matches_repo_relative_path()] ignores negation, so the caller
+    /// has to apply it, unless [`match_outcome()`][Self::match_outcome()] is used.
     pub fn is_negative(&self) -> bool {
         self.mode.contains(Mode::NEGATIVE)
     }
 
+    /// Return true if the pattern is anchored, so it's matched against the entire repository-relative path.
+    ///
+    /// This is the case for patterns starting with a slash like `/foo`, or for patterns containing a slash other than a
+    /// trailing one, like `foo/bar`. Patterns that aren't anchored, like `foo` or `foo/`, are matched against the basename
+    /// of a path and thus match at any depth.
+    pub fn is_anchored(&self) -> bool {
+        self.mode.contains(Mode::ABSOLUTE) || !self.mode.contains(Mode::NO_SUB_DIR)
+    }
+
+    /// Like [`matches_repo_relative_path()`][Self::matches_repo_relative_path()], but also apply negation to
+    /// produce a single outcome.
+    ///
+    /// Thus `!foo/` yields [`Match::Exclude`] for the directory `foo`, but [`Match::None`] for the file `foo`
+    /// as patterns that must match directories never match anything else, whether they are negated or not.
+    pub fn match_outcome<'a>(
+        &self,
+        path: impl Into<&'a BStr>,
+        basename_start_pos: Option<usize>,
+        is_dir: Option<bool>,
+        case: Case,
+    ) -> Match {
+        if !self.matches_repo_relative_path(path, basename_start_pos, is_dir, case) {
+            Match::None
+        } else if self.is_negative() {
+            Match::Exclude
+        } else {
+            Match::Include
+        }
+    }
+
     /// Match the given `path` which takes slashes (and only slashes) literally, and is relative to the repository root.
-    /// Note that `path` is assumed to be relative to the repository.
+    /// Note that `path` is assumed to be relative to the repository, and that negation isn't applied here, see [`is_negative()`][Self::is_negative()].
     ///
     /// We may take various shortcuts which is when `basename_start_pos` and `is_dir` come into play.
     /// `basename_start_pos` is the index at which the `path`'s basename starts.
     ///
     /// Lastly, `case` folding can be configured as well.
+    ///
+    /// In debug builds, it's asserted that `basename_start_pos` matches `path`, use
+    /// [`matches_repo_relative_path_unchecked()`][Self::matches_repo_relative_path_unchecked()] to skip this check.
     pub fn matches_repo_relative_path<'a>(
         &self,
         path: impl Into<&'a BStr>,
         basename_start_pos: Option<usize>,
         is_dir: Option<bool>,
         case: Case,
+    ) -> bool {
+        let path = path.into();
+        debug_assert_eq!(
+            basename_start_pos,
+            path.rfind_byte(b'/').map(|p| p + 1),
+            "BUG: invalid cached basename_start_pos provided"
+        );
+        self.matches_repo_relative_path_unchecked(path, basename_start_pos, is_dir, case)
+    }
+
+    /// Like [`matches_repo_relative_path()`][Self::matches_repo_relative_path()], but without asserting that
+    /// `basename_start_pos` is valid for `path` in debug builds.
+    ///
+    /// The caller must assure that `basename_start_pos` is the index right after the last `/` in `path`, or `None` if
+    /// there is no slash, just like `path.rfind_byte(b'/').map(|pos| pos + 1)` would compute it.
+    /// If this invariant doesn't hold, the outcome of matching patterns against
+    /// the basename is unspecified, but it won't panic.
+    pub fn matches_repo_relative_path_unchecked<'a>(
+        &self,
+        path: impl Into<&'a BStr>,
+        basename_start_pos: Option<usize>,
+        is_dir: Option<bool>,
+        case: Case,
     ) -> bool {
         let is_dir = is_dir.unwrap_or(false);
         if !is_dir && self.mode.contains(pattern::Mode::MUST_BE_DIR) {
@@ -84,21 +202,43 @@ impl Pattern {
                 Case::Sensitive => wildmatch::Mode::empty(),
             };
         let path = path.into();
-        debug_assert_eq!(
-            basename_start_pos,
-            path.rfind_byte(b'/').map(|p| p + 1),
-            "BUG: invalid cached basename_start_pos provided"
-        );
         debug_assert!(!path.starts_with(b"/"), "input path must be relative");
 
-        if self.mode.contains(pattern::Mode::NO_SUB_DIR) && !self.mode.contains(pattern::Mode::ABSOLUTE) {
-            let basename = &path[basename_start_pos.unwrap_or_default()..];
-            self.matches(basename, flags)
+        if !self.is_anchored() {
+            path.get(basename_start_pos.unwrap_or_default()..)
+                .map_or(false, |basename| self.matches(basename.as_bstr(), flags))
         } else {
             self.matches(path, flags)
         }
     }
 
+    /// Like [`matches_repo_relative_path()`][Self::matches_repo_relative_path()], but match `absolute_path` after stripping
+    /// `repo_root` from it, computing the basename position on the way.
+    ///
+    /// Return `None` if `absolute_path` isn't located within `repo_root`, or if it is `repo_root` itself.
+    /// Note that `repo_root` is compared byte by byte regardless of `case`, and that it may end with a slash or not.
+    pub fn matches_absolute_path<'a>(
+        &self,
+        absolute_path: impl Into<&'a BStr>,
+        repo_root: impl Into<&'a BStr>,
+        is_dir: Option<bool>,
+        case: Case,
+    ) -> Option<bool> {
+        let (absolute_path, repo_root) = (absolute_path.into(), repo_root.into());
+        let relative_path = absolute_path.strip_prefix(repo_root.as_bytes())?;
+        let relative_path = match relative_path.strip_prefix(b"/") {
+            Some(relative_path) => relative_path,
+            None if repo_root.ends_with(b"/") => relative_path,
+            None => return None,
+        };
+        if relative_path.is_empty() {
+            return None;
+        }
+        let relative_path = relative_path.as_bstr();
+        let basename_start_pos = relative_path.rfind_byte(b'/').map(|pos| pos + 1);
+        Some(self.matches_repo_relative_path(relative_path, basename_start_pos, is_dir, case))
+    }
+
     /// See if `value` matches this pattern in the given `mode`.
     ///
     /// `mode` can identify `value` as path which won't match the slash character, and can match
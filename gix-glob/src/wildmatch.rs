@@ -11,11 +11,16 @@ bitflags! {
 }
 
 pub(crate) mod function {
+    use std::{
+        collections::HashMap,
+        iter::{Enumerate, Map, Peekable},
+    };
+
     use bstr::{BStr, ByteSlice};
 
     use crate::wildmatch::Mode;
 
-    #[derive(Eq, PartialEq)]
+    #[derive(Eq, PartialEq, Clone, Copy)]
     enum Result {
         Match,
         NoMatch,
@@ -32,323 +37,473 @@ pub(crate) mod function {
 
     const NEGATE_CLASS: u8 = b'!';
 
-    fn match_recursive(pattern: &BStr, text: &BStr, mode: Mode) -> Result {
-        use self::Result::*;
-        let possibly_lowercase = |c: &u8| {
-            if mode.contains(Mode::IGNORE_CASE) {
-                c.to_ascii_lowercase()
-            } else {
-                *c
-            }
-        };
-        let mut p = pattern.iter().map(possibly_lowercase).enumerate().peekable();
-        let mut t = text.iter().map(possibly_lowercase).enumerate();
+    type Chars<'a> = Enumerate<Map<std::slice::Iter<'a, u8>, fn(&u8) -> u8>>;
+
+    /// What to do after a [`Frame`] made progress.
+    enum Step {
+        /// The frame is done, and the result is passed to its parent.
+        Return(Result),
+        /// Match the pattern from `pattern_start` against the text from `text_start`, both relative to the current frame,
+        /// and resume this frame with the result.
+        Call { pattern_start: usize, text_start: usize },
+    }
+
+    /// Where to resume a [`Frame`] once the result of its child frame is known.
+    enum Resume {
+        /// The frame wasn't started yet.
+        Start,
+        /// The child frame tried to match what follows a `**/`.
+        AfterDoubleStar,
+        /// The child frame tried to match what follows a `*` at the current position in the text.
+        StarLoop,
+    }
+
+    /// The outcome of handling the pattern character following a (double) star.
+    enum AfterStar {
+        /// Continue matching the remaining pattern and text one character at a time.
+        Continue,
+        /// Try all positions in the remaining text as match for the star.
+        EnterStarLoop,
+        /// The frame is done.
+        Return(Result),
+    }
 
-        while let Some((mut p_idx, mut p_ch)) = p.next() {
-            let (mut t_idx, mut t_ch) = match t.next() {
-                Some(c) => c,
-                None if p_ch != STAR => return AbortAll,
-                None => (text.len(), 0),
+    /// The state of matching `pattern` against `text`, one of which exists for each star that is currently being matched.
+    ///
+    /// These are kept on the heap to prevent stack overflows with patterns with lots of stars.
+    struct Frame<'a> {
+        /// The position of `pattern` in the pattern passed to [`wildmatch()`].
+        pattern_offset: usize,
+        /// The position of `text` in the value passed to [`wildmatch()`].
+        text_offset: usize,
+        pattern: &'a BStr,
+        text: &'a BStr,
+        lowercase: fn(&u8) -> u8,
+        p: Peekable<Chars<'a>>,
+        t: Chars<'a>,
+        resume: Resume,
+        p_idx: usize,
+        p_ch: u8,
+        t_idx: usize,
+        t_ch: u8,
+        match_slash: bool,
+        next: Option<(usize, u8)>,
+    }
+
+    impl<'a> Frame<'a> {
+        fn new(pattern: &'a BStr, pattern_offset: usize, text: &'a BStr, text_offset: usize, mode: Mode) -> Self {
+            let lowercase: fn(&u8) -> u8 = if mode.contains(Mode::IGNORE_CASE) {
+                |c| c.to_ascii_lowercase()
+            } else {
+                |c| *c
             };
+            Frame {
+                pattern_offset,
+                text_offset,
+                pattern,
+                text,
+                lowercase,
+                p: pattern.iter().map(lowercase).enumerate().peekable(),
+                t: text.iter().map(lowercase).enumerate(),
+                resume: Resume::Start,
+                p_idx: 0,
+                p_ch: 0,
+                t_idx: 0,
+                t_ch: 0,
+                match_slash: false,
+                next: None,
+            }
+        }
 
-            if p_ch == BACKSLASH {
-                match p.next() {
-                    Some((_p_idx, p_ch)) => {
-                        if p_ch != t_ch {
-                            return NoMatch;
-                        } else {
-                            continue;
+        /// Make progress, possibly using the `child` result of the frame created for the last returned [`Step::Call`].
+        fn step(&mut self, child: Option<Result>, mode: Mode) -> Step {
+            use self::Result::*;
+            match self.resume {
+                Resume::Start => self.scan(mode),
+                Resume::AfterDoubleStar => {
+                    if child == Some(Match) {
+                        return Step::Return(Match);
+                    }
+                    self.match_slash = true;
+                    match self.after_star() {
+                        AfterStar::Continue => self.scan(mode),
+                        AfterStar::EnterStarLoop => self.star_loop(),
+                        AfterStar::Return(res) => Step::Return(res),
+                    }
+                }
+                Resume::StarLoop => {
+                    let res = child.expect("resumed only after a child frame returned");
+                    if res != NoMatch {
+                        if !self.match_slash || res != AbortToStarStar {
+                            return Step::Return(res);
                         }
+                    } else if !self.match_slash && self.t_ch == SLASH {
+                        return Step::Return(AbortToStarStar);
                     }
-                    None => return NoMatch,
-                };
+                    match self.t.next() {
+                        Some(t) => {
+                            self.t_idx = t.0;
+                            self.t_ch = t.1;
+                        }
+                        None => return Step::Return(AbortAll),
+                    };
+                    self.star_loop()
+                }
             }
-            match p_ch {
-                b'?' => {
-                    if mode.contains(Mode::NO_MATCH_SLASH_LITERAL) && t_ch == SLASH {
-                        return NoMatch;
+        }
+
+        /// Handle the pattern character in `next` that follows a star, with `t_idx` being the position in the text the star
+        /// would start matching at.
+        fn after_star(&mut self) -> AfterStar {
+            use self::Result::*;
+            match self.next {
+                None => AfterStar::Return(if !self.match_slash && self.text[self.t_idx..].contains(&SLASH) {
+                    NoMatch
+                } else {
+                    Match
+                }),
+                Some((next_p_idx, next_p_ch)) => {
+                    self.p_idx = next_p_idx;
+                    self.p_ch = next_p_ch;
+                    if !self.match_slash && self.p_ch == SLASH {
+                        match self.text[self.t_idx..].find_byte(SLASH) {
+                            Some(distance_to_slash) => {
+                                for _ in self.t.by_ref().take(distance_to_slash) {}
+                                AfterStar::Continue
+                            }
+                            None => AfterStar::Return(NoMatch),
+                        }
                     } else {
-                        continue;
+                        AfterStar::EnterStarLoop
                     }
                 }
-                STAR => {
-                    let mut match_slash = !mode.contains(Mode::NO_MATCH_SLASH_LITERAL);
-                    match p.next() {
-                        Some((next_p_idx, next_p_ch)) => {
-                            let next;
-                            if next_p_ch == STAR {
-                                let leading_slash_idx = p_idx.checked_sub(1);
-                                while p.next_if(|(_, c)| *c == STAR).is_some() {}
-                                next = p.next();
-                                if !mode.contains(Mode::NO_MATCH_SLASH_LITERAL) {
-                                    match_slash = true;
-                                } else if leading_slash_idx.map_or(true, |idx| pattern[idx] == SLASH)
-                                    && next.map_or(true, |(_, c)| {
-                                        c == SLASH || (c == BACKSLASH && p.peek().map(|t| t.1) == Some(SLASH))
-                                    })
-                                {
-                                    if next.map_or(NoMatch, |(idx, _)| {
-                                        match_recursive(pattern[idx + 1..].as_bstr(), text[t_idx..].as_bstr(), mode)
-                                    }) == Match
-                                    {
-                                        return Match;
-                                    }
-                                    match_slash = true;
-                                } else {
-                                    match_slash = false;
-                                }
-                            } else {
-                                next = Some((next_p_idx, next_p_ch));
-                            }
+            }
+        }
 
-                            match next {
-                                None => {
-                                    return if !match_slash && text[t_idx..].contains(&SLASH) {
-                                        NoMatch
-                                    } else {
-                                        Match
-                                    };
-                                }
-                                Some((next_p_idx, next_p_ch)) => {
-                                    p_idx = next_p_idx;
-                                    p_ch = next_p_ch;
-                                    if !match_slash && p_ch == SLASH {
-                                        match text[t_idx..].find_byte(SLASH) {
-                                            Some(distance_to_slash) => {
-                                                for _ in t.by_ref().take(distance_to_slash) {}
-                                                continue;
-                                            }
-                                            None => return NoMatch,
-                                        }
-                                    }
-                                }
-                            }
+        /// Find the next position in the text at which the pattern following a star may match, and try to match it
+        /// in a child frame.
+        fn star_loop(&mut self) -> Step {
+            if !crate::parse::GLOB_CHARACTERS.contains(&self.p_ch) {
+                loop {
+                    if (!self.match_slash && self.t_ch == SLASH) || self.t_ch == self.p_ch {
+                        break;
+                    }
+                    match self.t.next() {
+                        Some(t) => {
+                            self.t_idx = t.0;
+                            self.t_ch = t.1;
                         }
-                        None => {
-                            return if !match_slash && text[t_idx..].contains(&SLASH) {
-                                NoMatch
+                        None => break,
+                    };
+                }
+                if self.t_ch != self.p_ch {
+                    return Step::Return(Result::NoMatch);
+                }
+            }
+            self.resume = Resume::StarLoop;
+            Step::Call {
+                pattern_start: self.p_idx,
+                text_start: self.t_idx,
+            }
+        }
+
+        /// Match the pattern against the text one character at a time, until a star is encountered or the frame is done.
+        fn scan(&mut self, mode: Mode) -> Step {
+            use self::Result::*;
+            let pattern = self.pattern;
+            let text = self.text;
+            while let Some((mut p_idx, mut p_ch)) = self.p.next() {
+                let (t_idx, t_ch) = match self.t.next() {
+                    Some(c) => c,
+                    None if p_ch != STAR => return Step::Return(AbortAll),
+                    None => (text.len(), 0),
+                };
+
+                if p_ch == BACKSLASH {
+                    match self.p.next() {
+                        Some((_p_idx, p_ch)) => {
+                            if p_ch != t_ch {
+                                return Step::Return(NoMatch);
                             } else {
-                                Match
+                                continue;
                             }
                         }
+                        None => return Step::Return(NoMatch),
+                    };
+                }
+                match p_ch {
+                    b'?' => {
+                        if mode.contains(Mode::NO_MATCH_SLASH_LITERAL) && t_ch == SLASH {
+                            return Step::Return(NoMatch);
+                        } else {
+                            continue;
+                        }
                     }
+                    STAR => {
+                        self.t_idx = t_idx;
+                        self.t_ch = t_ch;
+                        self.match_slash = !mode.contains(Mode::NO_MATCH_SLASH_LITERAL);
+                        match self.p.next() {
+                            Some((next_p_idx, next_p_ch)) => {
+                                if next_p_ch == STAR {
+                                    let leading_slash_idx = p_idx.checked_sub(1);
+                                    while self.p.next_if(|(_, c)| *c == STAR).is_some() {}
+                                    self.next = self.p.next();
+                                    if !mode.contains(Mode::NO_MATCH_SLASH_LITERAL) {
+                                        self.match_slash = true;
+                                    } else if leading_slash_idx.map_or(true, |idx| pattern[idx] == SLASH)
+                                        && self.next.map_or(true, |(_, c)| {
+                                            c == SLASH || (c == BACKSLASH && self.p.peek().map(|t| t.1) == Some(SLASH))
+                                        })
+                                    {
+                                        if let Some((idx, _)) = self.next {
+                                            self.resume = Resume::AfterDoubleStar;
+                                            return Step::Call {
+                                                pattern_start: idx + 1,
+                                                text_start: t_idx,
+                                            };
+                                        }
+                                        self.match_slash = true;
+                                    } else {
+                                        self.match_slash = false;
+                                    }
+                                } else {
+                                    self.next = Some((next_p_idx, next_p_ch));
+                                }
 
-                    return loop {
-                        if !crate::parse::GLOB_CHARACTERS.contains(&p_ch) {
-                            loop {
-                                if (!match_slash && t_ch == SLASH) || t_ch == p_ch {
-                                    break;
+                                match self.after_star() {
+                                    AfterStar::Continue => continue,
+                                    AfterStar::EnterStarLoop => return self.star_loop(),
+                                    AfterStar::Return(res) => return Step::Return(res),
                                 }
-                                match t.next() {
-                                    Some(t) => {
-                                        t_idx = t.0;
-                                        t_ch = t.1;
-                                    }
-                                    None => break,
-                                };
-                            }
-                            if t_ch != p_ch {
-                                return NoMatch;
                             }
-                        }
-                        let res = match_recursive(pattern[p_idx..].as_bstr(), text[t_idx..].as_bstr(), mode);
-                        if res != NoMatch {
-                            if !match_slash || res != AbortToStarStar {
-                                return res;
+                            None => {
+                                return Step::Return(if !self.match_slash && text[t_idx..].contains(&SLASH) {
+                                    NoMatch
+                                } else {
+                                    Match
+                                })
                             }
-                        } else if !match_slash && t_ch == SLASH {
-                            return AbortToStarStar;
                         }
-                        match t.next() {
+                    }
+                    BRACKET_OPEN => {
+                        match self.p.next() {
                             Some(t) => {
-                                t_idx = t.0;
-                                t_ch = t.1;
+                                p_idx = t.0;
+                                p_ch = t.1;
                             }
-                            None => break AbortAll,
+                            None => return Step::Return(AbortAll),
                         };
-                    };
-                }
-                BRACKET_OPEN => {
-                    match p.next() {
-                        Some(t) => {
-                            p_idx = t.0;
-                            p_ch = t.1;
-                        }
-                        None => return AbortAll,
-                    };
 
-                    if p_ch == b'^' {
-                        p_ch = NEGATE_CLASS;
-                    }
-                    let negated = p_ch == NEGATE_CLASS;
-                    let mut next = if negated { p.next() } else { Some((p_idx, p_ch)) };
-                    let mut prev_p_ch = 0;
-                    let mut matched = false;
-                    loop {
-                        match next {
-                            None => return AbortAll,
-                            Some((p_idx, mut p_ch)) => match p_ch {
-                                BACKSLASH => match p.next() {
-                                    Some((_, p_ch)) => {
-                                        if p_ch == t_ch {
-                                            matched = true
-                                        } else {
-                                            prev_p_ch = p_ch;
+                        if p_ch == b'^' {
+                            p_ch = NEGATE_CLASS;
+                        }
+                        let negated = p_ch == NEGATE_CLASS;
+                        let mut next = if negated { self.p.next() } else { Some((p_idx, p_ch)) };
+                        let mut prev_p_ch = 0;
+                        let mut matched = false;
+                        loop {
+                            match next {
+                                None => return Step::Return(AbortAll),
+                                Some((p_idx, mut p_ch)) => match p_ch {
+                                    BACKSLASH => match self.p.next() {
+                                        Some((_, p_ch)) => {
+                                            if p_ch == t_ch {
+                                                matched = true
+                                            } else {
+                                                prev_p_ch = p_ch;
+                                            }
                                         }
-                                    }
-                                    None => return AbortAll,
-                                },
-                                b'-' if prev_p_ch != 0
-                                    && p.peek().is_some()
-                                    && p.peek().map(|t| t.1) != Some(BRACKET_CLOSE) =>
-                                {
-                                    p_ch = p.next().expect("peeked").1;
-                                    if p_ch == BACKSLASH {
-                                        p_ch = match p.next() {
-                                            Some(t) => t.1,
-                                            None => return AbortAll,
-                                        };
-                                    }
-                                    if t_ch <= p_ch && t_ch >= prev_p_ch {
-                                        matched = true;
-                                    } else if mode.contains(Mode::IGNORE_CASE) && t_ch.is_ascii_lowercase() {
-                                        let t_ch_upper = t_ch.to_ascii_uppercase();
-                                        if (t_ch_upper <= p_ch.to_ascii_uppercase()
-                                            && t_ch_upper >= prev_p_ch.to_ascii_uppercase())
-                                            || (t_ch_upper <= prev_p_ch.to_ascii_uppercase()
-                                                && t_ch_upper >= p_ch.to_ascii_uppercase())
-                                        {
+                                        None => return Step::Return(AbortAll),
+                                    },
+                                    b'-' if prev_p_ch != 0
+                                        && self.p.peek().is_some()
+                                        && self.p.peek().map(|t| t.1) != Some(BRACKET_CLOSE) =>
+                                    {
+                                        p_ch = self.p.next().expect("peeked").1;
+                                        if p_ch == BACKSLASH {
+                                            p_ch = match self.p.next() {
+                                                Some(t) => t.1,
+                                                None => return Step::Return(AbortAll),
+                                            };
+                                        }
+                                        if t_ch <= p_ch && t_ch >= prev_p_ch {
                                             matched = true;
+                                        } else if mode.contains(Mode::IGNORE_CASE) && t_ch.is_ascii_lowercase() {
+                                            let t_ch_upper = t_ch.to_ascii_uppercase();
+                                            if (t_ch_upper <= p_ch.to_ascii_uppercase()
+                                                && t_ch_upper >= prev_p_ch.to_ascii_uppercase())
+                                                || (t_ch_upper <= prev_p_ch.to_ascii_uppercase()
+                                                    && t_ch_upper >= p_ch.to_ascii_uppercase())
+                                            {
+                                                matched = true;
+                                            }
                                         }
+                                        prev_p_ch = 0;
                                     }
-                                    prev_p_ch = 0;
-                                }
-                                BRACKET_OPEN if matches!(p.peek(), Some((_, COLON))) => {
-                                    p.next();
-                                    while p.peek().map_or(false, |t| t.1 != BRACKET_CLOSE) {
-                                        p.next();
-                                    }
-                                    let closing_bracket_idx = match p.next() {
-                                        Some((idx, _)) => idx,
-                                        None => return AbortAll,
-                                    };
-                                    const BRACKET__COLON__BRACKET: usize = 3;
-                                    if closing_bracket_idx - p_idx < BRACKET__COLON__BRACKET
-                                        || pattern[closing_bracket_idx - 1] != COLON
-                                    {
-                                        if t_ch == BRACKET_OPEN {
-                                            matched = true
+                                    BRACKET_OPEN if matches!(self.p.peek(), Some((_, COLON))) => {
+                                        self.p.next();
+                                        while self.p.peek().map_or(false, |t| t.1 != BRACKET_CLOSE) {
+                                            self.p.next();
                                         }
-                                        p = pattern[p_idx + 1..]
-                                            .iter()
-                                            .map(possibly_lowercase)
-                                            .enumerate()
-                                            .peekable();
-                                    } else {
-                                        let class = &pattern.as_bytes()[p_idx + 2..closing_bracket_idx - 1];
-                                        match class {
-                                            b"alnum" => {
-                                                if t_ch.is_ascii_alphanumeric() {
-                                                    matched = true;
-                                                }
+                                        let closing_bracket_idx = match self.p.next() {
+                                            Some((idx, _)) => idx,
+                                            None => return Step::Return(AbortAll),
+                                        };
+                                        const BRACKET__COLON__BRACKET: usize = 3;
+                                        if closing_bracket_idx - p_idx < BRACKET__COLON__BRACKET
+                                            || pattern[closing_bracket_idx - 1] != COLON
+                                        {
+                                            if t_ch == BRACKET_OPEN {
+                                                matched = true
                                             }
-                                            b"alpha" => {
-                                                if t_ch.is_ascii_alphabetic() {
-                                                    matched = true;
+                                            self.p =
+                                                pattern[p_idx + 1..].iter().map(self.lowercase).enumerate().peekable();
+                                        } else {
+                                            let class = &pattern.as_bytes()[p_idx + 2..closing_bracket_idx - 1];
+                                            match class {
+                                                b"alnum" => {
+                                                    if t_ch.is_ascii_alphanumeric() {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"blank" => {
-                                                if t_ch.is_ascii_whitespace() {
-                                                    matched = true;
+                                                b"alpha" => {
+                                                    if t_ch.is_ascii_alphabetic() {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"cntrl" => {
-                                                if t_ch.is_ascii_control() {
-                                                    matched = true;
+                                                b"blank" => {
+                                                    if t_ch == b' ' || t_ch == b'\t' {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"digit" => {
-                                                if t_ch.is_ascii_digit() {
-                                                    matched = true;
+                                                b"cntrl" => {
+                                                    if t_ch.is_ascii_control() {
+                                                        matched = true;
+                                                    }
+                                                }
+                                                b"digit" => {
+                                                    if t_ch.is_ascii_digit() {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
 
-                                            b"graph" => {
-                                                if t_ch.is_ascii_graphic() {
-                                                    matched = true;
+                                                b"graph" => {
+                                                    if t_ch.is_ascii_graphic() {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"lower" => {
-                                                if t_ch.is_ascii_lowercase() {
-                                                    matched = true;
+                                                b"lower" => {
+                                                    if t_ch.is_ascii_lowercase() {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"print" => {
-                                                if (0x20u8..=0x7e).contains(&t_ch) {
-                                                    matched = true;
+                                                b"print" => {
+                                                    if (0x20u8..=0x7e).contains(&t_ch) {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"punct" => {
-                                                if t_ch.is_ascii_punctuation() {
-                                                    matched = true;
+                                                b"punct" => {
+                                                    if t_ch.is_ascii_punctuation() {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"space" => {
-                                                if t_ch == b' ' {
-                                                    matched = true;
+                                                b"space" => {
+                                                    if matches!(t_ch, b' ' | b'\t' | b'\n' | b'\r') {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"upper" => {
-                                                if t_ch.is_ascii_uppercase()
-                                                    || mode.contains(Mode::IGNORE_CASE) && t_ch.is_ascii_lowercase()
-                                                {
-                                                    matched = true;
+                                                b"upper" => {
+                                                    if t_ch.is_ascii_uppercase()
+                                                        || mode.contains(Mode::IGNORE_CASE) && t_ch.is_ascii_lowercase()
+                                                    {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            b"xdigit" => {
-                                                if t_ch.is_ascii_hexdigit() {
-                                                    matched = true;
+                                                b"xdigit" => {
+                                                    if t_ch.is_ascii_hexdigit() {
+                                                        matched = true;
+                                                    }
                                                 }
-                                            }
-                                            _ => return AbortAll,
-                                        };
-                                        prev_p_ch = 0;
+                                                _ => return Step::Return(AbortAll),
+                                            };
+                                            prev_p_ch = 0;
+                                        }
                                     }
-                                }
-                                _ => {
-                                    prev_p_ch = p_ch;
-                                    if p_ch == t_ch {
-                                        matched = true;
+                                    _ => {
+                                        prev_p_ch = p_ch;
+                                        if p_ch == t_ch {
+                                            matched = true;
+                                        }
                                     }
-                                }
-                            },
-                        };
-                        next = p.next();
-                        if let Some((_, BRACKET_CLOSE)) = next {
-                            break;
+                                },
+                            };
+                            next = self.p.next();
+                            if let Some((_, BRACKET_CLOSE)) = next {
+                                break;
+                            }
+                        }
+                        if matched == negated || mode.contains(Mode::NO_MATCH_SLASH_LITERAL) && t_ch == SLASH {
+                            return Step::Return(NoMatch);
                         }
-                    }
-                    if matched == negated || mode.contains(Mode::NO_MATCH_SLASH_LITERAL) && t_ch == SLASH {
-                        return NoMatch;
-                    }
-                    continue;
-                }
-                non_glob_ch => {
-                    if non_glob_ch != t_ch {
-                        return NoMatch;
-                    } else {
                         continue;
                     }
+                    non_glob_ch => {
+                        if non_glob_ch != t_ch {
+                            return Step::Return(NoMatch);
+                        } else {
+                            continue;
+                        }
+                    }
                 }
             }
+            Step::Return(self.t.next().map(|_| NoMatch).unwrap_or(Match))
         }
-        t.next().map(|_| NoMatch).unwrap_or(Match)
     }
 
     /// Employ pattern matching to see if `value` matches `pattern`.
     ///
     /// `mode` can be used to adjust the way the matching is performed.
+    ///
+    /// Matching uses a heap-allocated stack with one frame per star in `pattern` instead of recursion, so even patterns
+    /// with lots of stars can't overflow the stack. Just like git, mismatches abort early, which bounds the amount of
+    /// backtracking for single stars. Patterns with `**` may still try to match the same portions of pattern and text
+    /// many times if slashes must be matched literally, which is why their results are remembered in that case.
     pub fn wildmatch(pattern: &BStr, value: &BStr, mode: Mode) -> bool {
-        match_recursive(pattern, value, mode) == Result::Match
+        let memoize = mode.contains(Mode::NO_MATCH_SLASH_LITERAL) && pattern.contains_str("**");
+        let mut results = HashMap::<(usize, usize), Result>::new();
+        let mut root = Frame::new(pattern, 0, value, 0, mode);
+        let mut stack = Vec::new();
+        let mut child = None;
+        loop {
+            let frame = stack.last_mut().unwrap_or(&mut root);
+            match frame.step(child.take(), mode) {
+                Step::Return(res) => match stack.pop() {
+                    Some(done) => {
+                        if memoize {
+                            results.insert((done.pattern_offset, done.text_offset), res);
+                        }
+                        child = Some(res);
+                    }
+                    None => return res == Result::Match,
+                },
+                Step::Call {
+                    pattern_start,
+                    text_start,
+                } => {
+                    let key = (frame.pattern_offset + pattern_start, frame.text_offset + text_start);
+                    match results.get(&key) {
+                        Some(res) => child = Some(*res),
+                        None => stack.push(Frame::new(
+                            pattern[key.0..].as_bstr(),
+                            key.0,
+                            value[key.1..].as_bstr(),
+                            key.1,
+                            mode,
+                        )),
+                    }
+                }
+            }
+        }
     }
 }
@@ -39,6 +39,28 @@ fn whitespace_only_is_ignored() {
     assert!(gix_glob::parse(b"\n\r\n\t\t   \n").is_none());
 }
 
+#[test]
+fn skip_reasons_are_provided_for_non_patterns() {
+    use gix_glob::pattern::SkipReason;
+    for (input, expected) in [
+        (&b""[..], SkipReason::Empty),
+        (b"!", SkipReason::Empty),
+        (b" \t\r\n", SkipReason::AllWhitespace),
+        (b"!  ", SkipReason::AllWhitespace),
+        (b"/", SkipReason::Empty),
+        (b"//", SkipReason::Empty),
+        (b"!/", SkipReason::Empty),
+    ] {
+        assert_eq!(Pattern::from_bytes_with_reason(input), Err(expected), "{input:?}");
+        assert_eq!(gix_glob::parse(input), None);
+    }
+    assert_eq!(
+        Pattern::from_bytes_with_reason(b"# hello").ok(),
+        gix_glob::parse(b"# hello"),
+        "comments are not known at this level"
+    );
+}
+
 #[test]
 fn hash_symbols_are_not_special() {
     assert_eq!(
@@ -105,6 +127,15 @@ fn trailing_slashes_are_marked_and_removed() {
     );
 }
 
+#[test]
+fn leading_whitespace_before_a_slash_is_part_of_the_pattern() {
+    assert_eq!(
+        gix_glob::parse(b"  /  "),
+        pat("  ", Mode::MUST_BE_DIR | Mode::NO_SUB_DIR, None),
+        "like in git, this matches directories named by two spaces, as only trailing spaces are insignificant"
+    );
+}
+
 #[test]
 fn trailing_spaces_are_ignored() {
     assert_eq!(gix_glob::parse(br"a   "), pat("a", Mode::NO_SUB_DIR, None));
@@ -153,3 +184,42 @@ fn trailing_spaces_can_be_escaped_to_be_literal() {
         "strange things like these work as well"
     );
 }
+
+mod options {
+    use gix_glob::{
+        pattern::{Mode, Options, SkipReason},
+        Pattern,
+    };
+
+    #[test]
+    fn negation_is_allowed_by_default() {
+        assert!(Options::default().allow_negation);
+        assert_eq!(
+            Pattern::from_bytes_with_options(b"!foo", Options::default()).ok(),
+            gix_glob::parse(b"!foo")
+        );
+        assert!(gix_glob::parse(b"!foo").expect("valid").is_negative());
+    }
+
+    #[test]
+    fn disallowed_negation_keeps_the_exclamation_mark_literally() {
+        let no_negation = Options { allow_negation: false };
+        assert_eq!(
+            Pattern::from_bytes_with_options(b"!foo", no_negation),
+            Ok(Pattern {
+                text: "!foo".into(),
+                mode: Mode::NO_SUB_DIR,
+                first_wildcard_pos: None,
+            })
+        );
+        assert_eq!(
+            Pattern::from_bytes_with_options(b"!", no_negation).map(|p| p.text),
+            Ok("!".into()),
+            "a single exclamation mark is a valid pattern then"
+        );
+        assert_eq!(
+            Pattern::from_bytes_with_options(b"", no_negation),
+            Err(SkipReason::Empty)
+        );
+    }
+}
@@ -131,6 +131,32 @@ fn matches_of_absolute_paths_work() {
     );
 }
 
+#[test]
+fn anchored_patterns_match_the_entire_path_and_others_match_basenames() {
+    let absolute = &pat("/foo");
+    assert!(absolute.is_anchored());
+    assert!(match_file(absolute, "foo", Case::Sensitive));
+    assert!(!match_file(absolute, "bar/foo", Case::Sensitive));
+
+    let with_slash = &pat("foo/bar");
+    assert!(
+        with_slash.is_anchored(),
+        "a slash in the middle anchors the pattern as well"
+    );
+    assert!(match_file(with_slash, "foo/bar", Case::Sensitive));
+    assert!(!match_file(with_slash, "baz/foo/bar", Case::Sensitive));
+
+    let basename = &pat("foo");
+    assert!(!basename.is_anchored());
+    assert!(match_file(basename, "foo", Case::Sensitive));
+    assert!(match_file(basename, "bar/baz/foo", Case::Sensitive));
+
+    assert!(
+        !pat("foo/").is_anchored(),
+        "trailing slashes only indicate directories and don't anchor"
+    );
+}
+
 #[test]
 fn basename_matches_from_end() {
     let pat = &pat("foo");
@@ -260,6 +286,97 @@ fn negated_patterns_are_handled_by_caller() {
         "the caller checks for the negative flag and acts accordingly"
     );
 }
+#[test]
+fn match_outcome_applies_negation_and_directory_rules() {
+    let outcome = |pattern: &str, path: &str, is_dir: bool| {
+        let path = path.into();
+        pat(pattern).match_outcome(path, basename_start_pos(path), Some(is_dir), Case::Sensitive)
+    };
+    assert_eq!(outcome("!foo/", "foo", true), pattern::Match::Exclude);
+    assert_eq!(
+        outcome("!foo/", "foo", false),
+        pattern::Match::None,
+        "negated directory patterns don't match files"
+    );
+    assert_eq!(outcome("!foo/", "a/foo", true), pattern::Match::Exclude);
+    assert_eq!(outcome("foo/", "foo", true), pattern::Match::Include);
+    assert_eq!(outcome("foo/", "foo", false), pattern::Match::None);
+    assert_eq!(outcome("!foo", "foo", false), pattern::Match::Exclude);
+    assert_eq!(outcome("!foo", "bar", false), pattern::Match::None);
+}
+
+#[test]
+fn absolute_paths_are_matched_relative_to_the_repository_root() {
+    let matches = |pattern: &str, path: &str, root: &str| {
+        pat(pattern).matches_absolute_path(path, root, Some(false), Case::Sensitive)
+    };
+    for root in ["/repo", "/repo/"] {
+        assert_eq!(matches("*.o", "/repo/a.o", root), Some(true));
+        assert_eq!(
+            matches("*.o", "/repo/dir/a.o", root),
+            Some(true),
+            "basenames match anywhere"
+        );
+        assert_eq!(matches("/dir/a.o", "/repo/dir/a.o", root), Some(true));
+        assert_eq!(
+            matches("/a.o", "/repo/dir/a.o", root),
+            Some(false),
+            "anchored to the root"
+        );
+        assert_eq!(matches("*.o", "/repo/a.c", root), Some(false));
+    }
+    assert_eq!(
+        pat("A.O").matches_absolute_path("/repo/dir/a.o", "/repo", None, Case::Fold),
+        Some(true)
+    );
+}
+
+#[test]
+fn absolute_paths_outside_of_the_repository_root_do_not_match() {
+    let matches = |path: &str, root: &str| pat("*").matches_absolute_path(path, root, Some(false), Case::Sensitive);
+    assert_eq!(matches("/other/a.o", "/repo"), None);
+    assert_eq!(
+        matches("/repository/a.o", "/repo"),
+        None,
+        "only entire path components count"
+    );
+    assert_eq!(
+        matches("/repo", "/repo"),
+        None,
+        "the root itself isn't inside of the root"
+    );
+    assert_eq!(matches("/repo/", "/repo/"), None);
+    assert_eq!(matches("/a.o", "/"), Some(true));
+}
+
+#[test]
+fn unchecked_matching_tolerates_invalid_basename_positions() {
+    let pattern = pat("*.o");
+    for path in ["a.o", "dir/a.o", "dir/sub/a.o"] {
+        let pos = basename_start_pos(path.into());
+        assert_eq!(
+            pattern.matches_repo_relative_path_unchecked(path, pos, None, Case::Sensitive),
+            pattern.matches_repo_relative_path(path, pos, None, Case::Sensitive),
+            "with valid positions, both variants are the same"
+        );
+    }
+    assert!(
+        !pattern.matches_repo_relative_path_unchecked("dir/a.o", Some(2), None, Case::Sensitive),
+        "a stale position matches the wrong basename"
+    );
+    assert!(
+        !pattern.matches_repo_relative_path_unchecked("a.o", Some(42), None, Case::Sensitive),
+        "positions out of bounds don't panic"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "BUG: invalid cached basename_start_pos provided")]
+fn checked_matching_asserts_valid_basename_positions_in_debug_builds() {
+    pat("*.o").matches_repo_relative_path("dir/a.o", Some(2), None, Case::Sensitive);
+}
+
 #[test]
 fn names_do_not_automatically_match_entire_directories() {
     // this feature is implemented with the directory stack.
@@ -240,6 +240,36 @@ fn corpus() {
     // 1 1 1 1 foo/bba/arr 'foo**'
 }
 
+#[test]
+fn posix_character_classes() {
+    let tests = [
+        (1u8, 1u8, 1u8, 1u8, "A", "[[:upper:]]"),
+        (0, 1, 0, 1, "a", "[[:upper:]]"),
+        (1, 1, 1, 1, "x", "[![:space:]]"),
+        (0, 0, 0, 0, " ", "[![:space:]]"),
+        (0, 0, 0, 0, "\t", "[![:space:]]"),
+        (1, 1, 1, 1, "\n", "[[:space:]]"),
+        (1, 1, 1, 1, "\r", "[[:space:]]"),
+        (1, 1, 1, 1, "\t", "[[:blank:]]"),
+        (0, 0, 0, 0, "\n", "[[:blank:]]"),
+        (1, 1, 1, 1, "7", "[[:digit:]x]"),
+        (1, 1, 1, 1, "x", "[[:digit:]x]"),
+        (0, 1, 0, 1, "X", "[[:digit:]x]"),
+        (0, 0, 0, 0, "y", "[[:digit:]x]"),
+        (1, 1, 1, 1, "file-1.txt", "file-[[:digit:]].[[:alpha:]]*"),
+    ];
+
+    for (path_match, path_imatch, glob_match, glob_imatch, text, pattern_text) in tests {
+        let (_pattern, actual) = multi_match(pattern_text, text);
+        assert!(!actual.any_panicked(), "{pattern_text:?} on {text:?}");
+        assert_eq!(
+            actual,
+            expect_multi(path_match, path_imatch, glob_match, glob_imatch),
+            "{pattern_text:?} on {text:?}"
+        );
+    }
+}
+
 #[test]
 fn brackets() {
     let (_pattern, actual) = multi_match(r"[B-a]", "A");
@@ -247,6 +277,57 @@ fn brackets() {
     assert_eq!(actual, expect_multi(0, 1, 0, 1));
 }
 
+#[test]
+fn pathological_patterns_do_not_overflow_the_stack() {
+    for mode in [wildmatch::Mode::empty(), wildmatch::Mode::NO_MATCH_SLASH_LITERAL] {
+        let many_stars = "*a".repeat(20_000);
+        assert!(
+            gix_glob::wildmatch(many_stars.as_str().into(), "a".repeat(20_000).as_str().into(), mode),
+            "each star can match the empty string"
+        );
+        assert!(!gix_glob::wildmatch(
+            format!("{many_stars}b").as_str().into(),
+            "a".repeat(40_000).as_str().into(),
+            mode
+        ));
+
+        let backtracking = format!("{}b", "a*".repeat(30));
+        assert!(!gix_glob::wildmatch(
+            backtracking.as_str().into(),
+            "a".repeat(100).as_str().into(),
+            mode
+        ));
+        assert!(!gix_glob::wildmatch(
+            format!("{}b", "**/".repeat(30)).as_str().into(),
+            "a/".repeat(100).as_str().into(),
+            mode
+        ));
+    }
+}
+
+#[test]
+fn double_stars_cross_directories_even_if_slashes_must_be_matched_literally() {
+    let path_match = |pattern: &str, text: &str| {
+        gix_glob::wildmatch(pattern.into(), text.into(), wildmatch::Mode::NO_MATCH_SLASH_LITERAL)
+    };
+    assert!(path_match("a/**/b", "a/x/y/b"));
+    assert!(path_match("a/**/b", "a/x/b"));
+    assert!(path_match("a/**/b", "a/b"), "`/**/` matches zero directories as well");
+    assert!(path_match("**/b", "a/x/b"));
+    assert!(path_match("a/**", "a/x/y/b"));
+    assert!(!path_match("a/*/b", "a/x/y/b"), "single stars don't cross directories");
+    assert!(path_match("a/*/b", "a/x/b"));
+    assert!(
+        !path_match("a**b", "a/x/b"),
+        "stars that aren't an entire path component behave like single stars"
+    );
+
+    for (pattern, is_match) in [("a/**/b", true), ("a/*/b", false)] {
+        let pattern = gix_glob::Pattern::from_bytes(pattern.as_bytes()).expect("valid");
+        assert_eq!(match_file_path(&pattern, "a/x/y/b", Case::Sensitive), is_match);
+    }
+}
+
 fn multi_match(pattern_text: &str, text: &str) -> (Pattern, MultiMatch) {
     let pattern = gix_glob::Pattern::from_bytes(pattern_text.as_bytes()).expect("valid (enough) pattern");
     let actual_path_match: MatchResult = catch_unwind(|| match_file_path(&pattern, text, Case::Sensitive)).into();
@@ -9,6 +9,12 @@ use std::{
 use crate::{borrowed::oid, Kind, SIZE_OF_SHA1_DIGEST};
 
 /// An owned hash identifying objects, most commonly Sha1
+///
+/// # Ordering
+///
+/// Ids of the same kind order lexicographically by their digest bytes. Ids of different kinds order by
+/// their kind first, in the order of declaration of the variants, and by their digest second. Use
+/// [`oid::cmp_bytes()`] to order by digest bytes only.
 #[derive(PartialEq, Eq, Ord, PartialOrd, Clone, Copy)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectId {
@@ -53,17 +59,67 @@ pub mod decode {
         pub fn from_hex(buffer: &[u8]) -> Result<ObjectId, Error> {
             use hex::FromHex;
             match buffer.len() {
-                40 => Ok(ObjectId::Sha1(<[u8; 20]>::from_hex(buffer).map_err(
-                    |err| match err {
-                        hex::FromHexError::InvalidHexCharacter { c, index } => Error::Invalid { c, index },
-                        hex::FromHexError::OddLength | hex::FromHexError::InvalidStringLength => {
-                            unreachable!("BUG: This is already checked")
-                        }
-                    },
-                )?)),
+                40 => Ok(ObjectId::Sha1(<[u8; 20]>::from_hex(buffer).map_err(into_error)?)),
                 len => Err(Error::InvalidHexEncodingLength(len)),
             }
         }
+
+        /// Decode the hexadecimal `buffer` into the existing `out` id, overwriting its digest in place.
+        ///
+        /// The length of `buffer` must match the hex length of the kind of `out`, which is left unchanged.
+        /// This is useful in hot loops which can keep a single id around instead of constructing a new one each time.
+        /// Note that `out` is in an unspecified state if an error is returned.
+        pub fn from_hex_into(buffer: &[u8], out: &mut ObjectId) -> Result<(), Error> {
+            if buffer.len() != out.kind().len_in_hex() {
+                return Err(Error::InvalidHexEncodingLength(buffer.len()));
+            }
+            hex::decode_to_slice(buffer, out.as_mut_slice()).map_err(into_error)
+        }
+
+        /// Decode the first 40 bytes of `buffer` encoded with hexadecimal notation and return the id along with
+        /// the remaining bytes of `buffer`, which is useful for parsers that process a longer buffer.
+        ///
+        /// It's an error if `buffer` is shorter than 40 bytes.
+        pub fn from_hex_prefix_consuming(buffer: &[u8]) -> Result<(ObjectId, &[u8]), Error> {
+            let hex_len = crate::Kind::Sha1.len_in_hex();
+            if buffer.len() < hex_len {
+                return Err(Error::InvalidHexEncodingLength(buffer.len()));
+            }
+            let (hex, rest) = buffer.split_at(hex_len);
+            Ok((ObjectId::from_hex(hex)?, rest))
+        }
+
+        /// Read exactly [`kind.len_in_bytes()`][crate::Kind::len_in_bytes()] raw digest bytes from `read` into a new id.
+        ///
+        /// This is useful to stream binary tables of object ids, like the ones in pack indices, without an intermediate buffer.
+        pub fn from_reader(read: &mut impl std::io::Read, kind: crate::Kind) -> std::io::Result<ObjectId> {
+            let mut id = ObjectId::null(kind);
+            read.read_exact(id.as_mut_slice())?;
+            Ok(id)
+        }
+
+        /// Read exactly [`kind.len_in_hex()`][crate::Kind::len_in_hex()] bytes encoded with hexadecimal notation
+        /// from `read` and decode them into a new id.
+        ///
+        /// Invalid hexadecimal characters are reported as error of kind [`InvalidData`][std::io::ErrorKind::InvalidData].
+        pub fn from_hex_reader(read: &mut impl std::io::Read, kind: crate::Kind) -> std::io::Result<ObjectId> {
+            let mut buf = crate::Kind::hex_buf();
+            let hex = &mut buf[..kind.len_in_hex()];
+            read.read_exact(hex)?;
+            let mut id = ObjectId::null(kind);
+            ObjectId::from_hex_into(hex, &mut id)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+            Ok(id)
+        }
+    }
+
+    fn into_error(err: hex::FromHexError) -> Error {
+        match err {
+            hex::FromHexError::InvalidHexCharacter { c, index } => Error::Invalid { c, index },
+            hex::FromHexError::OddLength | hex::FromHexError::InvalidStringLength => {
+                unreachable!("BUG: This is already checked")
+            }
+        }
     }
 
     impl FromStr for ObjectId {
@@ -108,6 +108,57 @@ impl oid {
         &self.bytes
     }
 
+    /// Compare only the digest bytes of this instance with `other` lexicographically, regardless of their kind.
+    ///
+    /// Digests which are a prefix of a longer digest order before it. Unlike the ordering of [`ObjectId`], which
+    /// orders by kind first, this is suitable for sorted indices which mix hashes of different kinds.
+    #[inline]
+    pub fn cmp_bytes(&self, other: &oid) -> std::cmp::Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+
+    /// Return the amount of leading bits that the digest of this instance shares with the one of `other`, which is the
+    /// length of both digests in bits if they are equal.
+    ///
+    /// Just like [`cmp_bytes()`][oid::cmp_bytes()], this only looks at the digest bytes, so the result is limited to the
+    /// length of the shorter digest if `other` is of a different kind.
+    #[inline]
+    pub fn common_prefix_bits(&self, other: &oid) -> usize {
+        self.bytes
+            .iter()
+            .zip(other.bytes.iter())
+            .position(|(a, b)| a != b)
+            .map_or_else(
+                || self.bytes.len().min(other.bytes.len()) * 8,
+                |pos| pos * 8 + (self.bytes[pos] ^ other.bytes[pos]).leading_zeros() as usize,
+            )
+    }
+
+    /// Return true if this is the id of an empty blob of our kind, see [`ObjectId::empty_blob()`].
+    #[inline]
+    pub fn is_empty_blob(&self) -> bool {
+        self.bytes == *ObjectId::empty_blob(self.kind()).as_slice()
+    }
+
+    /// Return true if this is the id of an empty tree of our kind, see [`ObjectId::empty_tree()`], like the one
+    /// diffs of root commits compare against.
+    #[inline]
+    pub fn is_empty_tree(&self) -> bool {
+        self.bytes == *ObjectId::empty_tree(self.kind()).as_slice()
+    }
+
+    /// Return the first 8 bytes of the hash interpreted as big-endian integer, which is the same on all platforms and in all runs.
+    ///
+    /// This is useful to deterministically distribute objects into buckets across processes and machines, unlike
+    /// the randomized hashers of the standard library. Note that this is not a cryptographic operation and its
+    /// result should only be used for sharding.
+    #[inline]
+    pub fn to_fingerprint(&self) -> u64 {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&self.bytes[..8]);
+        u64::from_be_bytes(buf)
+    }
+
     /// Return a type which can display itself in hexadecimal form with the `len` amount of characters.
     #[inline]
     pub fn to_hex_with_len(&self, len: usize) -> HexDisplay<'_> {
@@ -148,6 +199,20 @@ impl oid {
         out.write_all(&hex[..hex_len])
     }
 
+    /// Return ourselves in lowercase hexadecimal notation as array, without allocating.
+    ///
+    /// This is useful in hot paths that can't afford formatting machinery, like writing reference files.
+    #[inline]
+    pub fn to_hex_array(&self) -> [u8; SIZE_OF_SHA1_DIGEST * 2] {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut out = [0u8; SIZE_OF_SHA1_DIGEST * 2];
+        for (byte, hex) in self.bytes.iter().zip(out.chunks_exact_mut(2)) {
+            hex[0] = HEX[(byte >> 4) as usize];
+            hex[1] = HEX[(byte & 0xf) as usize];
+        }
+        out
+    }
+
     /// Returns a Sha1 digest with all bytes being initialized to zero.
     #[inline]
     pub(crate) fn null_sha1() -> &'static Self {
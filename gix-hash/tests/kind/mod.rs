@@ -14,3 +14,19 @@ mod from_hex_len {
         assert_eq!(Kind::from_hex_len(65), None);
     }
 }
+
+mod len_in_bytes {
+    use gix_hash::Kind;
+
+    #[test]
+    fn sha1_and_its_relation_to_hex_len() {
+        assert_eq!(Kind::Sha1.len_in_bytes(), 20);
+        assert_eq!(Kind::Sha1.len_in_bytes() * 2, Kind::Sha1.len_in_hex());
+        assert_eq!(
+            Kind::from_hex_len(Kind::Sha1.len_in_hex()),
+            Some(Kind::Sha1),
+            "a full hex hash maps to its kind"
+        );
+        assert_eq!(Kind::buf().len(), Kind::longest().len_in_bytes());
+    }
+}
@@ -42,6 +42,110 @@ mod from_hex {
     }
 }
 
+mod from_hex_into {
+    use gix_hash::{decode, Kind, ObjectId};
+
+    #[test]
+    fn overwrites_the_existing_id_in_place() {
+        let mut id = ObjectId::null(Kind::Sha1);
+        for hex in [
+            "1234567890abcdefaaaaaaaaaaaaaaaaaaaaaaaa",
+            "1234567890ABCDEFAAAAAAAAAAAAAAAAAAAAAAAB",
+        ] {
+            ObjectId::from_hex_into(hex.as_bytes(), &mut id).unwrap();
+            assert_eq!(id, ObjectId::from_hex(hex.as_bytes()).unwrap());
+        }
+    }
+
+    #[test]
+    fn errors_match_the_ones_of_from_hex() {
+        let mut id = ObjectId::null(Kind::Sha1);
+        assert!(matches!(
+            ObjectId::from_hex_into(b"abcd", &mut id).unwrap_err(),
+            decode::Error::InvalidHexEncodingLength(4)
+        ));
+        assert!(id.is_null(), "length errors leave the id untouched");
+        assert!(matches!(
+            ObjectId::from_hex_into(b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaz", &mut id).unwrap_err(),
+            decode::Error::Invalid { index: 39, c: 'z' }
+        ));
+    }
+}
+
+mod from_hex_prefix_consuming {
+    use gix_hash::{decode, ObjectId};
+
+    #[test]
+    fn returns_the_remaining_bytes() {
+        let hex = "1234567890abcdefaaaaaaaaaaaaaaaaaaaaaaaa";
+        let expected = ObjectId::from_hex(hex.as_bytes()).unwrap();
+        for (input, expected_rest) in [
+            (format!("{hex} refs/heads/main"), " refs/heads/main"),
+            (format!("{hex}\n"), "\n"),
+            (hex.to_owned(), ""),
+        ] {
+            let (id, rest) = ObjectId::from_hex_prefix_consuming(input.as_bytes()).unwrap();
+            assert_eq!(id, expected);
+            assert_eq!(rest, expected_rest.as_bytes());
+        }
+    }
+
+    #[test]
+    fn errors_if_there_are_not_enough_hex_characters() {
+        assert!(matches!(
+            ObjectId::from_hex_prefix_consuming(b"1234567890abcdef").unwrap_err(),
+            decode::Error::InvalidHexEncodingLength(16)
+        ));
+        assert!(matches!(
+            ObjectId::from_hex_prefix_consuming(b"1234567890abcdefaaaaaaaaaaaaaaaaaaaa zaaab\n").unwrap_err(),
+            decode::Error::Invalid { c: ' ', index: 36 }
+        ));
+    }
+}
+
+mod from_reader {
+    use std::io::{Cursor, ErrorKind, Read};
+
+    use gix_hash::{Kind, ObjectId};
+
+    const HEX: &str = "1234567890abcdefaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    #[test]
+    fn reads_exactly_the_raw_digest_bytes() {
+        let expected = ObjectId::from_hex(HEX.as_bytes()).unwrap();
+        let mut input = expected.as_slice().to_vec();
+        input.extend_from_slice(b"rest");
+        let mut cursor = Cursor::new(input);
+
+        assert_eq!(ObjectId::from_reader(&mut cursor, Kind::Sha1).unwrap(), expected);
+        let mut rest = Vec::new();
+        cursor.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"rest", "trailing bytes are left untouched");
+    }
+
+    #[test]
+    fn reads_exactly_the_hex_digest_bytes() {
+        let mut cursor = Cursor::new(format!("{HEX}\n"));
+        assert_eq!(
+            ObjectId::from_hex_reader(&mut cursor, Kind::Sha1).unwrap(),
+            ObjectId::from_hex(HEX.as_bytes()).unwrap()
+        );
+        assert_eq!(cursor.position(), 40);
+    }
+
+    #[test]
+    fn errors_on_short_reads_and_invalid_hex() {
+        let err = ObjectId::from_reader(&mut Cursor::new([0u8; 19]), Kind::Sha1).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        let err = ObjectId::from_hex_reader(&mut Cursor::new(&HEX[..39]), Kind::Sha1).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+        let err = ObjectId::from_hex_reader(&mut Cursor::new(HEX.replace('1', "z")), Kind::Sha1).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
+
 mod empty {
     use gix_features::hash::hasher;
     use gix_hash::{Kind, ObjectId};
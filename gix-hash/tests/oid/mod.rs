@@ -13,3 +13,133 @@ mod to_hex_with_len {
         );
     }
 }
+
+mod to_hex_array {
+    #[test]
+    fn matches_display() {
+        for id_hex in [
+            "0123456789abcdef123456789abcdef123456789",
+            "ffffffffffffffffffffffffffffffffffffffff",
+            "0000000000000000000000000000000000000000",
+        ] {
+            let id = gix_hash::ObjectId::from_hex(id_hex.as_bytes()).expect("valid input");
+            let hex = id.to_hex_array();
+            assert_eq!(std::str::from_utf8(&hex).expect("ascii"), id.to_string());
+            assert_eq!(&hex, id_hex.as_bytes());
+        }
+    }
+}
+
+mod to_fingerprint {
+    #[test]
+    fn uses_the_first_eight_bytes_in_big_endian_order() {
+        let id = gix_hash::ObjectId::from_hex(b"0123456789abcdef123456789abcdef123456789").expect("valid input");
+        assert_eq!(id.to_fingerprint(), 0x0123_4567_89ab_cdef);
+        assert_eq!(gix_hash::ObjectId::null(gix_hash::Kind::Sha1).to_fingerprint(), 0);
+    }
+}
+
+mod is_empty {
+    use gix_hash::{Kind, ObjectId};
+
+    #[test]
+    fn blob() {
+        assert!(ObjectId::empty_blob(Kind::Sha1).is_empty_blob());
+        assert!(gix_hash::oid::try_from_bytes(
+            b"\xe6\x9d\xe2\x9b\xb2\xd1\xd6\x43\x4b\x8b\x29\xae\x77\x5a\xd8\xc2\xe4\x8c\x53\x91"
+        )
+        .unwrap()
+        .is_empty_blob());
+        assert!(!ObjectId::empty_tree(Kind::Sha1).is_empty_blob());
+        assert!(!ObjectId::null(Kind::Sha1).is_empty_blob());
+    }
+
+    #[test]
+    fn tree() {
+        assert!(ObjectId::empty_tree(Kind::Sha1).is_empty_tree());
+        assert!(ObjectId::from_hex(b"4b825dc642cb6eb9a060e54bf8d69288fbee4904")
+            .unwrap()
+            .is_empty_tree());
+        assert!(!ObjectId::empty_blob(Kind::Sha1).is_empty_tree());
+        assert!(!ObjectId::null(Kind::Sha1).is_empty_tree());
+    }
+}
+
+mod common_prefix_bits {
+    use gix_hash::ObjectId;
+
+    fn id(hex: &str) -> ObjectId {
+        ObjectId::from_hex(hex.as_bytes()).expect("valid input")
+    }
+
+    #[test]
+    fn identical_ids_share_all_bits() {
+        let a = id("0123456789abcdef123456789abcdef123456789");
+        assert_eq!(a.common_prefix_bits(&a), 160);
+        let null = ObjectId::null(gix_hash::Kind::Sha1);
+        assert_eq!(null.common_prefix_bits(&null), 160);
+    }
+
+    #[test]
+    fn ids_differing_in_the_first_byte() {
+        let a = id("8000000000000000000000000000000000000000");
+        let null = ObjectId::null(gix_hash::Kind::Sha1);
+        assert_eq!(a.common_prefix_bits(&null), 0, "the very first bit differs");
+        assert_eq!(
+            id("0100000000000000000000000000000000000000").common_prefix_bits(&null),
+            7
+        );
+    }
+
+    #[test]
+    fn ids_diverging_mid_byte() {
+        let a = id("0123456789abcdef123456789abcdef123456789");
+        let b = id("0123456789abcdef12345e789abcdef123456789");
+        assert_eq!(a.common_prefix_bits(&b), 10 * 8 + 4);
+        assert_eq!(b.common_prefix_bits(&a), 10 * 8 + 4, "the operation is symmetric");
+        let c = id("0123456789abcdef123456789abcdef123456788");
+        assert_eq!(a.common_prefix_bits(&c), 159, "only the very last bit differs");
+    }
+}
+
+mod cmp_bytes {
+    use std::cmp::Ordering;
+
+    use gix_hash::ObjectId;
+
+    fn id(hex: &str) -> ObjectId {
+        ObjectId::from_hex(hex.as_bytes()).expect("valid input")
+    }
+
+    #[test]
+    fn same_kind_orders_lexicographically_and_agrees_with_ord() {
+        let a = id("0123456789abcdef123456789abcdef123456789");
+        let b = id("0123456789abcdef123456789abcdef12345678a");
+        let c = id("f000000000000000000000000000000000000000");
+        assert_eq!(a.cmp_bytes(&a), Ordering::Equal);
+        assert_eq!(a.cmp_bytes(&b), Ordering::Less);
+        assert_eq!(c.cmp_bytes(&b), Ordering::Greater);
+        for (lhs, rhs) in [(a, b), (b, c), (c, a), (a, a)] {
+            assert_eq!(
+                lhs.cmp_bytes(&rhs),
+                lhs.cmp(&rhs),
+                "within a kind, both orderings are the same"
+            );
+        }
+    }
+
+    #[test]
+    fn sorting_by_bytes_ignores_the_kind() {
+        // Only one kind exists right now, but sorting by bytes must not depend on it once more are added.
+        let mut ids = vec![
+            id("f000000000000000000000000000000000000000"),
+            ObjectId::null(gix_hash::Kind::Sha1),
+            id("0123456789abcdef123456789abcdef123456789"),
+        ];
+        let mut by_ord = ids.clone();
+        ids.sort_by(|a, b| a.cmp_bytes(b));
+        by_ord.sort();
+        assert_eq!(ids, by_ord);
+        assert!(ids[0].is_null());
+    }
+}
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use bstr::{BStr, ByteSlice, ByteVec};
+use bstr::{BStr, BString, ByteSlice, ByteVec};
 
 use crate::{entry, extension, Entry, PathStorage, State, Version};
 
@@ -144,6 +144,20 @@ impl State {
             .map(|idx| &mut self.entries[idx])
     }
 
+    /// Update the stat information of the stage-0 entry at `path` with the `metadata` of its file on disk, typically after
+    /// writing it to the worktree, and return `true` if the entry was found.
+    ///
+    /// Its id and flags remain unchanged.
+    pub fn refresh_stat(&mut self, path: &BStr, metadata: &std::fs::Metadata) -> bool {
+        match self.entry_mut_by_path_and_stage(path, 0) {
+            Some(entry) => {
+                entry.stat = entry::Stat::from_fs(metadata);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Push a new entry containing `stat`, `id`, `flags` and `mode` and `path` to the end of our storage, without performing
     /// any sanity checks. This means it's possible to push a new entry to the same path on the same stage and even after sorting
     /// the entries lookups may still return the wrong one of them unless the correct binary search criteria is chosen.
@@ -196,6 +210,147 @@ impl State {
                 .then_with(|| compare(a, b))
         });
     }
+
+    /// Insert all `entries`, whose paths are stored in `backing`, so that all entries remain sorted, replacing existing
+    /// entries with the same path and stage.
+    ///
+    /// `entries` may be in any order, and only the last of multiple entries with the same path and stage is kept.
+    /// As opposed to [pushing][State::dangerously_push_entry()] and [sorting][State::sort_entries()] each entry,
+    /// the sorted `entries` are merged into the existing ones in a single pass.
+    ///
+    /// The [tree extension][State::tree()] is [invalidated][extension::Tree::invalidate_path()] for the path of each inserted entry.
+    pub fn insert_entries(&mut self, mut entries: Vec<Entry>, backing: &crate::PathStorageRef) {
+        let cmp = |a: &Entry, a_backing: &crate::PathStorageRef, b: &Entry, b_backing: &crate::PathStorageRef| {
+            Entry::cmp_filepaths(a.path_in(a_backing), b.path_in(b_backing)).then_with(|| a.stage().cmp(&b.stage()))
+        };
+        entries.sort_by(|a, b| cmp(a, backing, b, backing));
+        entries.reverse();
+        entries.dedup_by(|a, b| cmp(a, backing, b, backing) == Ordering::Equal);
+        entries.reverse();
+
+        let previous = std::mem::take(&mut self.entries);
+        self.entries.reserve(previous.len() + entries.len());
+        let mut previous = previous.into_iter().peekable();
+        let mut entries = entries.into_iter().peekable();
+        loop {
+            let ordering = match (previous.peek(), entries.peek()) {
+                (Some(existing), Some(new)) => cmp(existing, &self.path_backing, new, backing),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+            let entry = match ordering {
+                Ordering::Less => previous.next().expect("peeked"),
+                Ordering::Greater => {
+                    let mut new = entries.next().expect("peeked");
+                    if let Some(tree) = self.tree.as_mut() {
+                        tree.invalidate_path(new.path_in(backing));
+                    }
+                    let path_start = self.path_backing.len();
+                    self.path_backing.extend_from_slice(new.path_in(backing));
+                    new.path = path_start..self.path_backing.len();
+                    new
+                }
+                Ordering::Equal => {
+                    let existing = previous.next().expect("peeked");
+                    let new = entries.next().expect("peeked");
+                    if let Some(tree) = self.tree.as_mut() {
+                        tree.invalidate_path(new.path_in(backing));
+                    }
+                    Entry {
+                        path: existing.path,
+                        ..new
+                    }
+                }
+            };
+            self.is_sparse |= entry.mode.is_sparse();
+            self.entries.push(entry);
+        }
+    }
+
+    /// Remove all entries within the directory `dir`, along with the entry at `dir` itself in case it's a file, and return
+    /// the amount of removed entries.
+    ///
+    /// This is similar to what `git rm -r dir` does to the index. The path backing is compacted afterwards, and the
+    /// [tree extension][State::tree()] is [invalidated][extension::Tree::invalidate_path()] for `dir`.
+    pub fn remove_prefix(&mut self, dir: &BStr) -> usize {
+        let dir = dir.strip_suffix(b"/").unwrap_or(dir);
+        let mut prefix = BString::from(dir);
+        prefix.push(b'/');
+
+        let path_backing = &self.path_backing;
+        let exact_start = self.entries.partition_point(|e| e.path_in(path_backing) < dir);
+        let exact_end = exact_start + self.entries[exact_start..].partition_point(|e| e.path_in(path_backing) == dir);
+        let prefix_start = exact_end + self.entries[exact_end..].partition_point(|e| e.path_in(path_backing) < prefix);
+        let prefix_end = prefix_start
+            + self.entries[prefix_start..].partition_point(|e| e.path_in(path_backing).starts_with(&prefix));
+
+        let num_removed = (exact_end - exact_start) + (prefix_end - prefix_start);
+        if num_removed == 0 {
+            return 0;
+        }
+        self.entries.drain(prefix_start..prefix_end);
+        self.entries.drain(exact_start..exact_end);
+        if let Some(tree) = self.tree.as_mut() {
+            tree.invalidate_path(dir.as_bstr());
+        }
+
+        let previous_backing = std::mem::take(&mut self.path_backing);
+        for entry in &mut self.entries {
+            let path_start = self.path_backing.len();
+            self.path_backing.extend_from_slice(entry.path_in(&previous_backing));
+            entry.path = path_start..self.path_backing.len();
+        }
+        self.is_sparse = self.entries.iter().any(|e| e.mode.is_sparse());
+        num_removed
+    }
+}
+
+/// Sparse checkout
+impl State {
+    /// Set the skip-worktree bit of all entries that aren't included by the sparse-checkout patterns, and clear it
+    /// for all others, similar to what `git sparse-checkout reapply` does to the index.
+    ///
+    /// `is_included(path, is_dir)` matches `path` against the patterns of the `.git/info/sparse-checkout` file and
+    /// returns `Some(true)` if the last matching pattern isn't negative, `Some(false)` if it is, or `None` if no
+    /// pattern matched. Just like git, if no pattern matches the path itself, the same is done for each of its parent
+    /// directories, starting at the closest one. This makes it work with patterns written in cone mode,
+    /// like `/*`, `!/*/` and `/dir/`, as well as with any other pattern.
+    ///
+    /// Note that sparse directory entries aren't created or expanded here.
+    pub fn set_sparse(&mut self, mut is_included: impl FnMut(&BStr, bool) -> Option<bool>) {
+        // Entries are sorted, so whole runs of them share their parent directory and its decision.
+        let mut last_parent: Option<(BString, bool)> = None;
+        for entry in &mut self.entries {
+            let path = entry.path_in(&self.path_backing);
+            let included = match is_included(path, entry.mode.is_sparse()) {
+                Some(included) => included,
+                None => {
+                    let parent = path
+                        .rfind_byte(b'/')
+                        .map(|pos| path[..pos].as_bstr())
+                        .unwrap_or_default();
+                    match &last_parent {
+                        Some((last, included)) if last == parent => *included,
+                        _ => {
+                            let mut included = false;
+                            let mut dir = parent;
+                            while !dir.is_empty() {
+                                if let Some(dir_included) = is_included(dir, true) {
+                                    included = dir_included;
+                                    break;
+                                }
+                                dir = dir.rfind_byte(b'/').map(|pos| dir[..pos].as_bstr()).unwrap_or_default();
+                            }
+                            last_parent = Some((parent.to_owned(), included));
+                            included
+                        }
+                    }
+                }
+            };
+            entry.set_skip_worktree(!included);
+        }
+    }
 }
 
 /// Extensions
@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+
+use bstr::BStr;
+
+use crate::{entry, Entry, State};
+
+/// A change between two index [states][State], as computed by [`State::changes_against()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Change<'a> {
+    /// The entry at `path` exists only in the newer state.
+    Added {
+        /// The repository-relative path of the entry.
+        path: &'a BStr,
+        /// The stage of the entry.
+        stage: entry::Stage,
+        /// The id of the added entry.
+        id: gix_hash::ObjectId,
+        /// The mode of the added entry.
+        mode: entry::Mode,
+    },
+    /// The entry at `path` exists only in the older state.
+    Removed {
+        /// The repository-relative path of the entry.
+        path: &'a BStr,
+        /// The stage of the entry.
+        stage: entry::Stage,
+        /// The id of the removed entry.
+        id: gix_hash::ObjectId,
+        /// The mode of the removed entry.
+        mode: entry::Mode,
+    },
+    /// The entry at `path` exists in both states, but its id or mode changed.
+    Modified {
+        /// The repository-relative path of the entry.
+        path: &'a BStr,
+        /// The stage of the entry.
+        stage: entry::Stage,
+        /// The id of the entry in the older state.
+        previous_id: gix_hash::ObjectId,
+        /// The id of the entry in the newer state.
+        id: gix_hash::ObjectId,
+        /// The mode of the entry in the older state.
+        previous_mode: entry::Mode,
+        /// The mode of the entry in the newer state.
+        mode: entry::Mode,
+    },
+}
+
+impl<'a> Change<'a> {
+    /// Return the repository-relative path of the entry that changed.
+    pub fn path(&self) -> &'a BStr {
+        match self {
+            Change::Added { path, .. } | Change::Removed { path, .. } | Change::Modified { path, .. } => path,
+        }
+    }
+}
+
+impl State {
+    /// Compute the changes from the `previous` state to this one, so that entries only present in this state are
+    /// [added][Change::Added], and entries only present in `previous` are [removed][Change::Removed].
+    ///
+    /// Entries are identified by path and stage, and are considered [modified][Change::Modified] if their id or mode differ.
+    /// Stat information and flags are ignored. As entries in both states are sorted, this is a linear merge of both entry lists,
+    /// yielding changes in the same order.
+    pub fn changes_against<'a>(&'a self, previous: &'a State) -> Vec<Change<'a>> {
+        let mut out = Vec::new();
+        let mut lhs = previous.entries().iter().peekable();
+        let mut rhs = self.entries().iter().peekable();
+        loop {
+            let ordering = match (lhs.peek(), rhs.peek()) {
+                (Some(old), Some(new)) => cmp(old, previous, new, self),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+            match ordering {
+                Ordering::Less => {
+                    let old = lhs.next().expect("peeked");
+                    out.push(Change::Removed {
+                        path: old.path(previous),
+                        stage: old.stage(),
+                        id: old.id,
+                        mode: old.mode,
+                    });
+                }
+                Ordering::Greater => {
+                    let new = rhs.next().expect("peeked");
+                    out.push(Change::Added {
+                        path: new.path(self),
+                        stage: new.stage(),
+                        id: new.id,
+                        mode: new.mode,
+                    });
+                }
+                Ordering::Equal => {
+                    let (old, new) = (lhs.next().expect("peeked"), rhs.next().expect("peeked"));
+                    if old.id != new.id || old.mode != new.mode {
+                        out.push(Change::Modified {
+                            path: new.path(self),
+                            stage: new.stage(),
+                            previous_id: old.id,
+                            id: new.id,
+                            previous_mode: old.mode,
+                            mode: new.mode,
+                        });
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+fn cmp(lhs: &Entry, lhs_state: &State, rhs: &Entry, rhs_state: &State) -> Ordering {
+    Entry::cmp_filepaths(lhs.path(lhs_state), rhs.path(rhs_state)).then_with(|| lhs.stage().cmp(&rhs.stage()))
+}
@@ -27,6 +27,11 @@ mod error {
             actual_checksum: gix_hash::ObjectId,
             expected_checksum: gix_hash::ObjectId,
         },
+        #[error("Index checksum should have been {expected}, but the hash of its content was {actual}")]
+        ContentChecksumMismatch {
+            actual: gix_hash::ObjectId,
+            expected: gix_hash::ObjectId,
+        },
     }
 }
 pub use error::Error;
@@ -34,6 +39,113 @@ use gix_features::parallel::InOrderIter;
 
 use crate::util::read_u32;
 
+/// A way to specify which of the optional extensions to decode.
+#[derive(Debug, Copy, Clone)]
+pub enum Extensions {
+    /// Decode all known extensions.
+    All,
+    /// Only decode the given optional extensions, with each extension being marked by a boolean flag, and skip all others.
+    ///
+    /// # Note: mandatory extensions
+    ///
+    /// Mandatory extensions, like `link` or `sdir`, may not be configured here as they are always decoded
+    /// for the index to be read correctly.
+    Given {
+        /// Decode the tree-cache extension.
+        tree_cache: bool,
+        /// Decode the resolve-undo extension.
+        resolve_undo: bool,
+        /// Decode the untracked-cache extension, which can be particularly expensive.
+        untracked_cache: bool,
+        /// Decode the filesystem-monitor extension.
+        fs_monitor: bool,
+    },
+    /// Decode no optional extension at all, for when only entries are needed.
+    None,
+}
+
+impl Default for Extensions {
+    fn default() -> Self {
+        Extensions::All
+    }
+}
+
+impl Extensions {
+    /// Returns `true` if the optional extension with `signature` should be decoded.
+    pub fn should_decode(&self, signature: extension::Signature) -> bool {
+        match self {
+            Extensions::None => false,
+            Extensions::All => true,
+            Extensions::Given {
+                tree_cache,
+                resolve_undo,
+                untracked_cache,
+                fs_monitor,
+            } => match signature {
+                extension::tree::SIGNATURE => *tree_cache,
+                extension::resolve_undo::SIGNATURE => *resolve_undo,
+                extension::untracked_cache::SIGNATURE => *untracked_cache,
+                extension::fs_monitor::SIGNATURE => *fs_monitor,
+                _ => false,
+            },
+        }
+    }
+}
+
+/// A way to specify how to handle bytes past the trailing checksum of an index file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Trailing {
+    /// Fail if there are any bytes past the checksum, just like git.
+    Forbid,
+    /// Ignore all bytes past the checksum and report them as [warning][Warning::TrailingBytes], to be able to recover
+    /// the content of an index file that had bytes appended to it.
+    ///
+    /// As the trailing bytes could be anything, extensions are decoded only as long as their headers look plausible,
+    /// and the checksum is expected right after the last of them.
+    Ignore,
+}
+
+impl Default for Trailing {
+    fn default() -> Self {
+        Trailing::Forbid
+    }
+}
+
+/// A way to specify if and how to verify the checksum at the end of an index file against its content.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Checksum {
+    /// Don't hash the content of the index at all, which is what git does when reading an index.
+    Skip,
+    /// Hash the content of the index and report a mismatch with the stored checksum as [warning][Warning::ChecksumMismatch],
+    /// to be able to recover the content of an index file that was partially corrupted.
+    Warn,
+    /// Hash the content of the index and fail with [`Error::ContentChecksumMismatch`] if it doesn't match the stored checksum.
+    Fail,
+}
+
+impl Default for Checksum {
+    fn default() -> Self {
+        Checksum::Skip
+    }
+}
+
+/// Problems that were tolerated when decoding an index state [from bytes][State::from_bytes_with_warnings()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// The given amount of bytes past the trailing checksum were ignored due to [`Trailing::Ignore`].
+    TrailingBytes {
+        /// The amount of bytes that were ignored.
+        len: usize,
+    },
+    /// The hash of the index content didn't match its checksum, which was tolerated due to [`Checksum::Warn`].
+    ChecksumMismatch {
+        /// The hash of the content of the index.
+        actual: gix_hash::ObjectId,
+        /// The checksum stored at the end of the index.
+        expected: gix_hash::ObjectId,
+    },
+}
+
 /// Options to define how to decode an index state [from bytes][State::from_bytes()].
 #[derive(Default, Clone, Copy)]
 pub struct Options {
@@ -50,12 +162,32 @@ pub struct Options {
     ///
     /// We will abort reading this file if it doesn't match.
     pub expected_checksum: Option<gix_hash::ObjectId>,
+    /// Configures which optional extensions to decode, with all others being skipped.
+    pub extensions: Extensions,
+    /// Configures how to handle bytes past the trailing checksum of the index.
+    pub trailing_bytes: Trailing,
+    /// Configures if the content of the index is hashed and compared to the checksum stored at its end.
+    ///
+    /// This is skipped by default as hashing is costly, and git only verifies this checksum when checking the integrity of a repository.
+    /// Null checksums, as written if `index.skipHash` is set, are never verified.
+    pub verify_checksum: Checksum,
 }
 
 impl State {
     /// Decode an index state from `data` and store `timestamp` in the resulting instance for pass-through, assuming `object_hash`
     /// to be used through the file.
     pub fn from_bytes(
+        data: &[u8],
+        timestamp: FileTime,
+        object_hash: gix_hash::Kind,
+        options: Options,
+    ) -> Result<(Self, gix_hash::ObjectId), Error> {
+        Self::from_bytes_with_warnings(data, timestamp, object_hash, options)
+            .map(|(state, checksum, _)| (state, checksum))
+    }
+
+    /// Like [`from_bytes()`][State::from_bytes()], but also return all problems that were tolerated due to `options`.
+    pub fn from_bytes_with_warnings(
         data: &[u8],
         timestamp: FileTime,
         object_hash: gix_hash::Kind,
@@ -63,8 +195,12 @@ impl State {
             thread_limit,
             min_extension_block_in_bytes_for_threading,
             expected_checksum,
+            extensions,
+            trailing_bytes,
+            verify_checksum,
         }: Options,
-    ) -> Result<(Self, gix_hash::ObjectId), Error> {
+    ) -> Result<(Self, gix_hash::ObjectId, Vec<Warning>), Error> {
+        let input = data;
         let (version, num_entries, post_header_data) = header::decode(data, object_hash)?;
         let start_of_extensions = extension::end_of_index_entry::decode(data, object_hash);
 
@@ -89,7 +225,9 @@ impl State {
                                 scope
                                     .builder()
                                     .name("gix-index.from_bytes.load-extensions".into())
-                                    .spawn(|_| extension::decode::all(extensions_data, object_hash))
+                                    .spawn(|_| {
+                                        extension::decode::all(extensions_data, object_hash, extensions, trailing_bytes)
+                                    })
                                     .expect("valid name")
                             }
                         });
@@ -187,7 +325,9 @@ impl State {
                     };
                     let ext_res = extension_loading
                         .map(|thread| thread.join().unwrap())
-                        .unwrap_or_else(|| extension::decode::all(extensions_data, object_hash));
+                        .unwrap_or_else(|| {
+                            extension::decode::all(extensions_data, object_hash, extensions, trailing_bytes)
+                        });
                     (entries_res, ext_res)
                 })
                 .unwrap(); // this unwrap is for panics - if these happened we are done anyway.
@@ -202,11 +342,22 @@ impl State {
                     object_hash,
                     version,
                 )?;
-                let (ext, data) = extension::decode::all(data, object_hash)?;
+                let (ext, data) = extension::decode::all(data, object_hash, extensions, trailing_bytes)?;
                 (entries, ext, data)
             }
         };
 
+        let end_of_content = input.len() - data.len();
+        let mut warnings = Vec::new();
+        let data = match trailing_bytes {
+            Trailing::Ignore if data.len() > object_hash.len_in_bytes() => {
+                warnings.push(Warning::TrailingBytes {
+                    len: data.len() - object_hash.len_in_bytes(),
+                });
+                &data[..object_hash.len_in_bytes()]
+            }
+            Trailing::Ignore | Trailing::Forbid => data,
+        };
         if data.len() != object_hash.len_in_bytes() {
             return Err(Error::UnexpectedTrailerLength {
                 expected: object_hash.len_in_bytes(),
@@ -223,6 +374,26 @@ impl State {
                 });
             }
         }
+        if verify_checksum != Checksum::Skip && !checksum.is_null() {
+            let mut hasher = gix_features::hash::hasher(object_hash);
+            hasher.update(&input[..end_of_content]);
+            let actual = gix_hash::ObjectId::from(hasher.digest());
+            if actual != checksum {
+                match verify_checksum {
+                    Checksum::Fail => {
+                        return Err(Error::ContentChecksumMismatch {
+                            actual,
+                            expected: checksum,
+                        })
+                    }
+                    Checksum::Warn => warnings.push(Warning::ChecksumMismatch {
+                        actual,
+                        expected: checksum,
+                    }),
+                    Checksum::Skip => unreachable!("we don't hash if verification is skipped"),
+                }
+            }
+        }
         let EntriesOutcome {
             entries,
             path_backing,
@@ -254,6 +425,7 @@ impl State {
                 fs_monitor,
             },
             checksum,
+            warnings,
         ))
     }
 }
@@ -8,6 +8,8 @@ mod flags;
 pub(crate) use flags::at_rest;
 pub use flags::Flags;
 
+mod stat;
+
 mod write;
 
 /// The time component in a [`Stat`] struct.
@@ -60,6 +62,53 @@ mod access {
         pub fn stage(&self) -> entry::Stage {
             self.flags.stage()
         }
+
+        /// Return true if the entry is assumed to be unchanged, so that the worktree file isn't checked for modifications.
+        pub fn is_assume_unchanged(&self) -> bool {
+            self.flags.contains(entry::Flags::ASSUME_VALID)
+        }
+
+        /// Return true if the entry isn't supposed to be present in the worktree, typically due to sparse checkouts.
+        pub fn is_skip_worktree(&self) -> bool {
+            self.flags.contains(entry::Flags::SKIP_WORKTREE)
+        }
+
+        /// Return true if the entry was added with `git add --intent-to-add`, so it's known but has no content yet.
+        pub fn is_intent_to_add(&self) -> bool {
+            self.flags.contains(entry::Flags::INTENT_TO_ADD)
+        }
+    }
+
+    /// Mutation
+    impl Entry {
+        /// Set or clear the assume-unchanged bit, similar to `git update-index --[no-]assume-unchanged`.
+        pub fn set_assume_unchanged(&mut self, value: bool) -> &mut Self {
+            self.flags.set(entry::Flags::ASSUME_VALID, value);
+            self
+        }
+
+        /// Set or clear the skip-worktree bit, similar to `git update-index --[no-]skip-worktree`.
+        ///
+        /// As this flag is extended, entries with it will cause the index to be written as V3 at least.
+        pub fn set_skip_worktree(&mut self, value: bool) -> &mut Self {
+            self.set_extended_flag(entry::Flags::SKIP_WORKTREE, value)
+        }
+
+        /// Set or clear the intent-to-add bit.
+        ///
+        /// As this flag is extended, entries with it will cause the index to be written as V3 at least.
+        pub fn set_intent_to_add(&mut self, value: bool) -> &mut Self {
+            self.set_extended_flag(entry::Flags::INTENT_TO_ADD, value)
+        }
+
+        fn set_extended_flag(&mut self, flag: entry::Flags, value: bool) -> &mut Self {
+            self.flags.set(flag, value);
+            let has_extended_flags = self
+                .flags
+                .intersects(entry::Flags::SKIP_WORKTREE | entry::Flags::INTENT_TO_ADD);
+            self.flags.set(entry::Flags::EXTENDED, has_extended_flags);
+            self
+        }
     }
 }
 
@@ -21,4 +21,17 @@ impl Mode {
     pub fn is_sparse(&self) -> bool {
         *self == Self::DIR
     }
+
+    /// Return the equivalent of this mode for use in tree entries, or `None` if it isn't exactly one of the known modes.
+    pub fn to_tree_entry_mode(&self) -> Option<gix_object::tree::EntryMode> {
+        use gix_object::tree::EntryMode;
+        Some(match *self {
+            Self::DIR => EntryMode::Tree,
+            Self::FILE => EntryMode::Blob,
+            Self::FILE_EXECUTABLE => EntryMode::BlobExecutable,
+            Self::SYMLINK => EntryMode::Link,
+            Self::COMMIT => EntryMode::Commit,
+            _ => return None,
+        })
+    }
 }
@@ -0,0 +1,51 @@
+use filetime::FileTime;
+
+use crate::entry::{Stat, Time};
+
+impl Stat {
+    /// Obtain the stat information as it would be stored in the index from the `metadata` of a file on disk.
+    ///
+    /// Like git, all values are truncated to 32 bits, and information that isn't available on the current platform
+    /// is set to 0. On platforms without a change time, the creation time is used, or the modification time if
+    /// that's unavailable too.
+    pub fn from_fs(metadata: &std::fs::Metadata) -> Self {
+        let mtime = FileTime::from_last_modification_time(metadata);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            Stat {
+                mtime: mtime.into(),
+                ctime: Time {
+                    secs: metadata.ctime() as u32,
+                    nsecs: metadata.ctime_nsec() as u32,
+                },
+                dev: metadata.dev() as u32,
+                ino: metadata.ino() as u32,
+                uid: metadata.uid(),
+                gid: metadata.gid(),
+                size: metadata.size() as u32,
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            Stat {
+                mtime: mtime.into(),
+                ctime: FileTime::from_creation_time(metadata).unwrap_or(mtime).into(),
+                dev: 0,
+                ino: 0,
+                uid: 0,
+                gid: 0,
+                size: metadata.len() as u32,
+            }
+        }
+    }
+}
+
+impl From<FileTime> for Time {
+    fn from(time: FileTime) -> Self {
+        Time {
+            secs: time.unix_seconds() as u32,
+            nsecs: time.nanoseconds(),
+        }
+    }
+}
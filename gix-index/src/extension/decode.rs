@@ -29,8 +29,18 @@ pub use error::Error;
 pub(crate) fn all(
     maybe_beginning_of_extensions: &[u8],
     object_hash: gix_hash::Kind,
+    extensions: crate::decode::Extensions,
+    trailing_bytes: crate::decode::Trailing,
 ) -> Result<(Outcome, &[u8]), Error> {
-    let mut ext_iter = match extension::Iter::new_without_checksum(maybe_beginning_of_extensions, object_hash) {
+    let ext_iter = match trailing_bytes {
+        crate::decode::Trailing::Forbid => {
+            extension::Iter::new_without_checksum(maybe_beginning_of_extensions, object_hash)
+        }
+        crate::decode::Trailing::Ignore => Some(extension::Iter::new(
+            &maybe_beginning_of_extensions[..plausible_len(maybe_beginning_of_extensions, object_hash)],
+        )),
+    };
+    let mut ext_iter = match ext_iter {
         Some(iter) => iter,
         None => return Ok((Outcome::default(), maybe_beginning_of_extensions)),
     };
@@ -38,6 +48,7 @@ pub(crate) fn all(
     let mut ext = Outcome::default();
     for (signature, ext_data) in ext_iter.by_ref() {
         match signature {
+            optional if optional[0].is_ascii_uppercase() && !extensions.should_decode(optional) => {}
             extension::tree::SIGNATURE => {
                 ext.tree = extension::tree::decode(ext_data, object_hash);
             }
@@ -69,6 +80,21 @@ pub(crate) fn all(
     Ok((ext, &maybe_beginning_of_extensions[ext_iter.consumed..]))
 }
 
+/// Return the length of all extensions at the beginning of `data` which are followed by at least a checksum of `object_hash`,
+/// stopping at the first extension header that doesn't look like one.
+fn plausible_len(data: &[u8], object_hash: gix_hash::Kind) -> usize {
+    let mut len = 0;
+    while let Some(ext_header) = data.get(len..len + 4 + 4) {
+        let (signature, size) = ext_header.split_at(4);
+        let end = len + 4 + 4 + from_be_u32(size) as usize;
+        if !signature.iter().all(u8::is_ascii_alphabetic) || end + object_hash.len_in_bytes() > data.len() {
+            break;
+        }
+        len = end;
+    }
+    len
+}
+
 #[derive(Default)]
 pub(crate) struct Outcome {
     pub tree: Option<extension::Tree>,
@@ -1,4 +1,6 @@
-use crate::extension::Signature;
+use bstr::{BStr, ByteSlice};
+
+use crate::extension::{Signature, Tree};
 
 /// The signature for tree extensions
 pub const SIGNATURE: Signature = *b"TREE";
@@ -11,6 +13,25 @@ pub use decode::decode;
 
 mod write;
 
+impl Tree {
+    /// Invalidate this tree and all trees leading to the entry at `path`, so they are recomputed when writing trees next time,
+    /// and remove the tree at `path` if there is one, as it was replaced or removed along with all of its entries.
+    ///
+    /// This mirrors what git does whenever an entry is added, replaced or removed.
+    pub fn invalidate_path(&mut self, path: &BStr) {
+        self.num_entries = None;
+        match path.find_byte(b'/') {
+            Some(pos) => {
+                let (name, rest) = (&path[..pos], &path[pos + 1..]);
+                if let Some(child) = self.children.iter_mut().find(|c| c.name.as_slice() == name) {
+                    child.invalidate_path(rest.as_bstr());
+                }
+            }
+            None => self.children.retain(|c| c.name.as_slice() != path.as_bytes()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
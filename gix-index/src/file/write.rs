@@ -8,6 +8,8 @@ use crate::{write, File, Version};
 pub enum Error {
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error("The index file at '{}' is locked by another process", resource_path.display())]
+    Locked { resource_path: std::path::PathBuf },
     #[error("Could not acquire lock for index file")]
     AcquireLock(#[from] gix_lock::acquire::Error),
     #[error("Could not commit lock for index file")]
@@ -32,16 +34,24 @@ impl File {
 
     /// Write ourselves to the path we were read from after acquiring a lock, using `options`.
     ///
+    /// Just like git, we write to a lock file next to our path first, sync it to disk and finally move it into place, so
+    /// the index on disk is always complete even if we are interrupted. If the lock file exists already, we fail immediately
+    /// with [`Error::Locked`].
+    ///
     /// Note that the hash produced will be stored which is why we need to be mutable.
     pub fn write(&mut self, options: write::Options) -> Result<(), Error> {
-        let mut lock = std::io::BufWriter::new(gix_lock::File::acquire_to_update_resource(
-            &self.path,
-            gix_lock::acquire::Fail::Immediately,
-            None,
-        )?);
+        let lock = gix_lock::File::acquire_to_update_resource(&self.path, gix_lock::acquire::Fail::Immediately, None)
+            .map_err(|err| match err {
+            gix_lock::acquire::Error::PermanentlyLocked { resource_path, .. } => Error::Locked { resource_path },
+            err => Error::AcquireLock(err),
+        })?;
+        let mut lock = std::io::BufWriter::new(lock);
         let (version, digest) = self.write_to(&mut lock, options)?;
         match lock.into_inner() {
-            Ok(lock) => lock.commit()?,
+            Ok(mut lock) => {
+                lock.with_mut(|file| file.sync_all())?;
+                lock.commit()?
+            }
             Err(err) => return Err(err.into_error().into()),
         };
         self.state.version = version;
@@ -22,6 +22,9 @@ pub mod entry;
 
 mod access;
 
+///
+pub mod changes;
+
 mod init;
 
 ///
@@ -30,9 +33,20 @@ pub mod decode;
 ///
 pub mod verify;
 
+///
+pub mod to_tree;
+
 ///
 pub mod write;
 
+/// Read only the header of the index file in `data`, returning its version and the amount of entries it claims to contain,
+/// without decoding any of the entries or extensions.
+///
+/// `object_hash` is needed to validate the minimal size of `data`, as the header itself doesn't convey the kind of hash.
+pub fn read_header(data: &[u8], object_hash: gix_hash::Kind) -> Result<(Version, u32), decode::header::Error> {
+    decode::header::decode(data, object_hash).map(|(version, num_entries, _)| (version, num_entries))
+}
+
 /// All known versions of a git index file.
 #[derive(PartialEq, Eq, Debug, Hash, Ord, PartialOrd, Clone, Copy)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
@@ -140,6 +154,9 @@ mod impls {
     }
 }
 
+#[cfg(feature = "serde1")]
+mod serde_impls;
+
 pub(crate) mod util {
     use std::convert::TryInto;
 
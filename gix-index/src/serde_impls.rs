@@ -0,0 +1,84 @@
+//! Serialization of the logical [`State`], which doesn't reproduce the bytes of an index file on disk.
+//!
+//! Entries are stored along with their paths, and extensions aren't serialized at all.
+use bstr::BString;
+use filetime::FileTime;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{entry, Entry, State, Version};
+
+#[derive(Serialize, Deserialize)]
+struct StateRepr {
+    object_hash: gix_hash::Kind,
+    version: Version,
+    timestamp: TimestampRepr,
+    entries: Vec<EntryRepr>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TimestampRepr {
+    secs: i64,
+    nsecs: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EntryRepr {
+    path: BString,
+    stat: entry::Stat,
+    id: gix_hash::ObjectId,
+    flags: u32,
+    mode: u32,
+}
+
+impl Serialize for State {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StateRepr {
+            object_hash: self.object_hash,
+            version: self.version,
+            timestamp: TimestampRepr {
+                secs: self.timestamp.unix_seconds(),
+                nsecs: self.timestamp.nanoseconds(),
+            },
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| EntryRepr {
+                    path: entry.path(self).to_owned(),
+                    stat: entry.stat,
+                    id: entry.id,
+                    flags: entry.flags.bits(),
+                    mode: entry.mode.bits(),
+                })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for State {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = StateRepr::deserialize(deserializer)?;
+        let mut state = State::new(repr.object_hash);
+        state.version = repr.version;
+        state.timestamp = FileTime::from_unix_time(repr.timestamp.secs, repr.timestamp.nsecs);
+        state.entries.reserve(repr.entries.len());
+        for entry in repr.entries {
+            let flags = entry::Flags::from_bits(entry.flags)
+                .ok_or_else(|| de::Error::custom(format!("invalid entry flags: {:#x}", entry.flags)))?;
+            let mode = entry::Mode::from_bits(entry.mode)
+                .ok_or_else(|| de::Error::custom(format!("invalid entry mode: {:o}", entry.mode)))?;
+            let path_start = state.path_backing.len();
+            state.path_backing.extend_from_slice(&entry.path);
+            state.is_sparse |= mode.is_sparse();
+            state.entries.push(Entry {
+                stat: entry.stat,
+                id: entry.id,
+                flags,
+                mode,
+                path: path_start..state.path_backing.len(),
+            });
+        }
+        state.sort_entries();
+        Ok(state)
+    }
+}
@@ -0,0 +1,145 @@
+use bstr::{BStr, BString, ByteSlice};
+use gix_object::WriteTo;
+
+use crate::{entry, State};
+
+/// The error returned by [State::to_tree()][crate::State::to_tree()].
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum Error {
+    #[error("Entry '{path}' has stage {stage} as it's conflicted, and can't be written into a tree")]
+    Conflict { path: BString, stage: entry::Stage },
+    #[error("Entry '{path}' has mode {mode:o} which can't be represented in a tree")]
+    UnsupportedMode { path: BString, mode: u32 },
+    #[error("Entry '{path}' has an empty path component")]
+    EmptyPathComponent { path: BString },
+}
+
+/// The trees produced by [State::to_tree()][crate::State::to_tree()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    /// The id of the root tree.
+    pub id: gix_hash::ObjectId,
+    /// All trees along with their ids, including the root tree, with the trees of sub-directories listed before their parents.
+    ///
+    /// The caller is expected to write them into an object database as needed.
+    pub trees: Vec<(gix_hash::ObjectId, gix_object::Tree)>,
+}
+
+struct Directory<'a> {
+    name: &'a BStr,
+    tree: gix_object::Tree,
+}
+
+impl State {
+    /// Turn all entries into a hierarchy of trees similar to what `git write-tree` would do, without writing them anywhere.
+    ///
+    /// Entries that are marked for removal or that were added with intent-to-add are ignored, as is the tree cache
+    /// extension, and it's an error if there are entries with a stage other than 0.
+    /// As entries are sorted, all trees are created in a single pass.
+    pub fn to_tree(&self) -> Result<Outcome, Error> {
+        let mut trees = Vec::new();
+        let mut stack = vec![Directory {
+            name: "".into(),
+            tree: gix_object::Tree::empty(),
+        }];
+        let mut buf = Vec::new();
+
+        for entry in self.entries() {
+            if entry
+                .flags
+                .intersects(entry::Flags::REMOVE | entry::Flags::INTENT_TO_ADD)
+            {
+                continue;
+            }
+            let full_path = entry.path(self);
+            if entry.stage() != 0 {
+                return Err(Error::Conflict {
+                    path: full_path.to_owned(),
+                    stage: entry.stage(),
+                });
+            }
+            let mode = entry.mode.to_tree_entry_mode().ok_or_else(|| Error::UnsupportedMode {
+                path: full_path.to_owned(),
+                mode: entry.mode.bits(),
+            })?;
+            let path = if entry.mode.is_sparse() {
+                full_path.strip_suffix(b"/").unwrap_or(full_path)
+            } else {
+                full_path
+            };
+            let mut components = path.split(|b| *b == b'/').map(ByteSlice::as_bstr);
+            let filename = components.next_back().expect("split yields at least one item");
+            if filename.is_empty() || components.clone().any(|c| c.is_empty()) {
+                return Err(Error::EmptyPathComponent {
+                    path: full_path.to_owned(),
+                });
+            }
+
+            let mut depth = 1;
+            let mut components = components.peekable();
+            while let Some(component) = components.peek() {
+                if stack.get(depth).map_or(true, |dir| dir.name != *component) {
+                    break;
+                }
+                depth += 1;
+                components.next();
+            }
+            while stack.len() > depth {
+                pop_directory(&mut stack, &mut trees, &mut buf, self.object_hash);
+            }
+            stack.extend(components.map(|name| Directory {
+                name,
+                tree: gix_object::Tree::empty(),
+            }));
+            stack
+                .last_mut()
+                .expect("root is always present")
+                .tree
+                .entries
+                .push(gix_object::tree::Entry {
+                    mode,
+                    filename: filename.to_owned(),
+                    oid: entry.id,
+                });
+        }
+
+        while stack.len() > 1 {
+            pop_directory(&mut stack, &mut trees, &mut buf, self.object_hash);
+        }
+        let root = stack.pop().expect("root is always present");
+        let id = compute_id(&root.tree, &mut buf, self.object_hash);
+        trees.push((id, root.tree));
+        Ok(Outcome { id, trees })
+    }
+}
+
+fn pop_directory(
+    stack: &mut Vec<Directory<'_>>,
+    trees: &mut Vec<(gix_hash::ObjectId, gix_object::Tree)>,
+    buf: &mut Vec<u8>,
+    object_hash: gix_hash::Kind,
+) {
+    let Directory { name, tree } = stack.pop().expect("only called with non-root directories on the stack");
+    let id = compute_id(&tree, buf, object_hash);
+    trees.push((id, tree));
+    stack
+        .last_mut()
+        .expect("parent is present")
+        .tree
+        .entries
+        .push(gix_object::tree::Entry {
+            mode: gix_object::tree::EntryMode::Tree,
+            filename: name.to_owned(),
+            oid: id,
+        });
+}
+
+fn compute_id(tree: &gix_object::Tree, buf: &mut Vec<u8>, object_hash: gix_hash::Kind) -> gix_hash::ObjectId {
+    buf.clear();
+    tree.write_to(&mut *buf).expect("writing to a vec never fails");
+    let mut hasher = gix_features::hash::hasher(object_hash);
+    hasher.update(&gix_object::encode::loose_header(gix_object::Kind::Tree, buf.len()));
+    hasher.update(buf);
+    gix_hash::ObjectId::from(hasher.digest())
+}
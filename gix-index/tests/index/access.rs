@@ -1,3 +1,5 @@
+use gix_index::changes::Change;
+
 use crate::index::Fixture;
 
 #[test]
@@ -49,3 +51,299 @@ fn sort_entries() {
         "we can find the correct entry now"
     );
 }
+
+#[test]
+fn changes_against() {
+    let previous = Fixture::Generated("v2").open();
+    let current = Fixture::Generated("v2_more_files").open();
+    let changes = current.changes_against(&previous);
+    assert_eq!(
+        changes.iter().map(|c| c.path()).collect::<Vec<_>>(),
+        ["b", "c", "d/a", "d/b", "d/c"],
+        "'a' is unchanged, and all other files were added"
+    );
+    assert!(changes.iter().all(|c| matches!(c, Change::Added { stage: 0, .. })));
+
+    assert!(
+        previous
+            .changes_against(&current)
+            .iter()
+            .all(|c| matches!(c, Change::Removed { .. })),
+        "reversing the direction turns additions into removals"
+    );
+    assert!(current.changes_against(&current).is_empty());
+
+    let mut modified = Fixture::Generated("v2_more_files").open();
+    let entry = &mut modified.entries_mut()[1];
+    entry.id = gix_hash::ObjectId::empty_tree(gix_hash::Kind::Sha1);
+    entry.mode = gix_index::entry::Mode::FILE_EXECUTABLE;
+    assert_eq!(
+        modified.changes_against(&current),
+        [Change::Modified {
+            path: "b".into(),
+            stage: 0,
+            previous_id: current.entries()[1].id,
+            id: gix_hash::ObjectId::empty_tree(gix_hash::Kind::Sha1),
+            previous_mode: gix_index::entry::Mode::FILE,
+            mode: gix_index::entry::Mode::FILE_EXECUTABLE,
+        }]
+    );
+}
+
+#[test]
+fn to_tree_matches_the_tree_computed_by_git() {
+    for fixture in [
+        Fixture::Generated("v2"),
+        Fixture::Generated("v2_more_files"),
+        Fixture::Generated("v2_all_file_kinds"),
+        Fixture::Generated("v4_more_files_IEOT"),
+    ] {
+        let state = fixture.open();
+        let expected = state.tree().expect("fixtures have a valid tree cache");
+        let outcome = state.to_tree().unwrap();
+        assert_eq!(outcome.id, expected.id, "{}", fixture.to_name());
+        assert_eq!(
+            outcome.trees.last().map(|(id, _)| *id),
+            Some(outcome.id),
+            "the root tree comes last"
+        );
+        assert_eq!(
+            outcome.trees.len(),
+            count_trees(expected),
+            "all sub-trees are created as well"
+        );
+    }
+
+    fn count_trees(tree: &gix_index::extension::Tree) -> usize {
+        1 + tree.children.iter().map(count_trees).sum::<usize>()
+    }
+}
+
+#[test]
+fn to_tree_ignores_removed_entries_and_rejects_conflicts() {
+    let mut state = Fixture::Generated("v2_more_files").open();
+    for entry in state.entries_mut().iter_mut().skip(3) {
+        entry.flags.insert(gix_index::entry::Flags::REMOVE);
+    }
+    let outcome = state.to_tree().unwrap();
+    assert_eq!(outcome.trees.len(), 1, "the entries in 'd' are gone");
+    assert_eq!(outcome.trees[0].1.entries.len(), 3);
+
+    let err = Fixture::Loose("conflicting-file").open().to_tree().unwrap_err();
+    assert!(matches!(err, gix_index::to_tree::Error::Conflict { stage: 1, .. }));
+}
+
+#[test]
+fn refresh_stat() -> crate::Result {
+    let mut state = Fixture::Generated("v2_more_files").open();
+    let dir = gix_testtools::tempfile::tempdir()?;
+    let file_path = dir.path().join("a");
+    std::fs::write(&file_path, b"content")?;
+    let metadata = std::fs::symlink_metadata(&file_path)?;
+
+    let before = state.entry_by_path_and_stage("a".into(), 0).expect("present").clone();
+    assert_ne!(
+        before.stat,
+        gix_index::entry::Stat::from_fs(&metadata),
+        "the stat doesn't match initially"
+    );
+    assert!(state.refresh_stat("a".into(), &metadata));
+
+    let after = state.entry_by_path_and_stage("a".into(), 0).expect("present");
+    assert_eq!(
+        after.stat,
+        gix_index::entry::Stat::from_fs(&std::fs::symlink_metadata(&file_path)?),
+        "it matches the file on disk now"
+    );
+    assert_eq!(after.stat.size, 7);
+    assert_eq!(after.id, before.id, "the id is untouched");
+    assert_eq!(after.flags, before.flags, "the flags are untouched");
+
+    assert!(!state.refresh_stat("does-not-exist".into(), &metadata));
+    Ok(())
+}
+
+#[test]
+fn insert_entries() {
+    let mut file = Fixture::Generated("v2_more_files").open();
+    let mut batch = gix_index::State::new(gix_hash::Kind::Sha1);
+    let template = file.entry(0).clone();
+    let new_id = gix_hash::ObjectId::empty_tree(gix_hash::Kind::Sha1);
+    for path in ["z", "d/b", "0", "d/aa", "c"] {
+        batch.dangerously_push_entry(template.stat, new_id, template.flags, template.mode, path.into());
+    }
+    batch.dangerously_push_entry(template.stat, template.id, template.flags, template.mode, "z".into());
+
+    assert!(
+        file.tree().and_then(|t| t.num_entries).is_some(),
+        "the tree extension is valid initially"
+    );
+    file.insert_entries(batch.entries().to_vec(), batch.path_backing());
+    assert!(file.verify_entries().is_ok(), "the sort order is maintained");
+    assert_eq!(
+        file.entries().iter().map(|e| e.path(&file)).collect::<Vec<_>>(),
+        ["0", "a", "b", "c", "d/a", "d/aa", "d/b", "d/c", "z"]
+    );
+    for (path, expected_id) in [
+        ("a", template.id),
+        ("c", new_id),
+        ("d/b", new_id),
+        ("d/c", template.id),
+        ("z", template.id),
+    ] {
+        assert_eq!(
+            file.entry_by_path_and_stage(path.into(), 0).expect("present").id,
+            expected_id,
+            "existing entries with the same path are replaced, and the last of duplicate new entries wins: {path}"
+        );
+    }
+
+    let tree = roundtrip(&file)
+        .tree()
+        .cloned()
+        .expect("tree extension is still present");
+    assert_eq!(tree.num_entries, None, "the root tree changed");
+    let d = tree
+        .children
+        .iter()
+        .find(|t| t.name.as_slice() == b"d")
+        .expect("d exists");
+    assert_eq!(d.num_entries, None, "d/b was replaced and d/aa was added");
+}
+
+fn roundtrip(file: &gix_index::File) -> gix_index::State {
+    let mut buf = Vec::new();
+    file.write_to(&mut buf, Default::default())
+        .expect("in-memory writes succeed");
+    gix_index::State::from_bytes(
+        &buf,
+        filetime::FileTime::now(),
+        gix_hash::Kind::Sha1,
+        Default::default(),
+    )
+    .expect("valid index")
+    .0
+}
+
+#[test]
+fn remove_prefix() {
+    let mut file = Fixture::Generated("v2_more_files").open();
+    let num_path_bytes = file.path_backing().len();
+    let tree = file.tree().expect("tree extension present");
+    assert_eq!(tree.num_entries, Some(6), "the tree extension is valid initially");
+    assert!(tree.children.iter().any(|t| t.name.as_slice() == b"d"));
+    assert_eq!(file.remove_prefix("d/".into()), 3);
+    let tree = roundtrip(&file)
+        .tree()
+        .cloned()
+        .expect("tree extension is still present");
+    assert_eq!(tree.num_entries, None, "the root tree changed and has to be recomputed");
+    assert!(tree.children.is_empty(), "the tree of the removed directory is gone");
+    assert!(file.verify_entries().is_ok());
+    assert_eq!(
+        file.entries().iter().map(|e| e.path(&file)).collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+    assert_eq!(
+        file.path_backing().len(),
+        num_path_bytes - "d/ad/bd/c".len(),
+        "the path backing is compacted"
+    );
+
+    assert_eq!(file.remove_prefix("d".into()), 0, "nothing left to remove");
+    assert_eq!(file.remove_prefix("b".into()), 1, "files are removed as well");
+    assert_eq!(
+        file.entries().iter().map(|e| e.path(&file)).collect::<Vec<_>>(),
+        ["a", "c"]
+    );
+    assert_eq!(
+        file.entry_by_path_and_stage("c".into(), 0).map(|e| e.path(&file)),
+        Some("c".into())
+    );
+}
+
+#[test]
+fn set_sparse_flags_the_same_entries_as_git() {
+    for name in ["v3_skip_worktree", "v3_sparse_index_non_cone"] {
+        let index_path = crate::fixture_index_path(name);
+        let patterns = gix_attributes::PatternList::<gix_attributes::Ignore>::from_file(
+            index_path.with_file_name("info").join("sparse-checkout"),
+            None,
+            false,
+            &mut Vec::new(),
+        )
+        .unwrap()
+        .expect("sparse-checkout file present");
+
+        let mut file = Fixture::Generated(name).open();
+        let skipped_by_git = skipped_paths(&file);
+        assert!(!skipped_by_git.is_empty(), "git skips some entries in {name}");
+        for entry in file.entries_mut() {
+            entry.set_skip_worktree(false);
+        }
+
+        file.set_sparse(matcher(&patterns));
+        assert_eq!(skipped_paths(&file), skipped_by_git, "{name}");
+    }
+
+    fn skipped_paths(file: &gix_index::File) -> Vec<bstr::BString> {
+        file.entries()
+            .iter()
+            .filter(|e| e.is_skip_worktree())
+            .map(|e| e.path(file).to_owned())
+            .collect()
+    }
+}
+
+fn matcher(
+    patterns: &gix_attributes::PatternList<gix_attributes::Ignore>,
+) -> impl FnMut(&bstr::BStr, bool) -> Option<bool> + '_ {
+    use bstr::ByteSlice;
+    |path, is_dir| {
+        patterns
+            .pattern_matching_relative_path(
+                path,
+                path.rfind_byte(b'/').map(|pos| pos + 1),
+                Some(is_dir),
+                gix_glob::pattern::Case::Sensitive,
+            )
+            .map(|m| !m.pattern.is_negative())
+    }
+}
+
+#[test]
+fn set_sparse_with_cone_patterns() {
+    let mut file = Fixture::Generated("v4_more_files_IEOT").open();
+    let patterns = gix_attributes::PatternList::<gix_attributes::Ignore>::from_bytes(
+        b"/*\n!/*/\n/d/\n!/d/*/\n",
+        "sparse-checkout",
+        None,
+    );
+    file.set_sparse(matcher(&patterns));
+    assert_eq!(
+        file.entries()
+            .iter()
+            .map(|e| (e.path(&file), e.is_skip_worktree()))
+            .collect::<Vec<_>>(),
+        [
+            ("a", false),
+            ("b", false),
+            ("c", false),
+            ("d/a", false),
+            ("d/b", false),
+            ("d/c", false),
+            ("d/last/123", true),
+            ("d/last/34", true),
+            ("d/last/6", true),
+            ("x", false),
+        ]
+        .map(|(path, skipped)| (path.into(), skipped)),
+        "files in the root and directly in the included directory stay, but not those in its subdirectories"
+    );
+    assert!(
+        file.entries()
+            .iter()
+            .all(|e| e.is_skip_worktree() == e.flags.contains(gix_index::entry::Flags::EXTENDED)),
+        "the bit is extended and the entry is marked accordingly"
+    );
+}
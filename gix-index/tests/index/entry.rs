@@ -15,3 +15,65 @@ mod time {
         }
     }
 }
+
+mod flags {
+    use filetime::FileTime;
+    use gix_index::{Entry, File, State, Version};
+
+    use crate::index::Fixture;
+
+    fn write_and_read(file: &File) -> (Version, File) {
+        let mut buf = Vec::new();
+        let (version, _digest) = file.write_to(&mut buf, Default::default()).unwrap();
+        let (state, _) = State::from_bytes(&buf, FileTime::now(), gix_hash::Kind::Sha1, Default::default()).unwrap();
+        (version, File::from_state(state, file.path()))
+    }
+
+    fn roundtrip(toggle: impl Fn(&mut Entry, bool) -> &mut Entry, is_set: impl Fn(&Entry) -> bool, version: Version) {
+        let mut file = Fixture::Generated("v2_more_files").open();
+        assert!(!is_set(&file.entries()[0]));
+        toggle(&mut file.entries_mut()[0], true);
+        assert!(is_set(&file.entries()[0]));
+
+        let (actual_version, mut actual) = write_and_read(&file);
+        assert_eq!(actual_version, version);
+        assert!(
+            is_set(&actual.entries()[0]),
+            "the flag survives being written and read back"
+        );
+        assert!(!is_set(&actual.entries()[1]), "other entries are unaffected");
+
+        toggle(&mut actual.entries_mut()[0], false);
+        assert!(!is_set(&actual.entries()[0]));
+        let (actual_version, actual) = write_and_read(&actual);
+        assert_eq!(actual_version, Version::V2, "without extended flags, we are back to V2");
+        assert!(!is_set(&actual.entries()[0]));
+    }
+
+    #[test]
+    fn assume_unchanged_roundtrips_without_changing_the_version() {
+        roundtrip(Entry::set_assume_unchanged, Entry::is_assume_unchanged, Version::V2);
+    }
+
+    #[test]
+    fn skip_worktree_roundtrips_and_needs_v3() {
+        roundtrip(Entry::set_skip_worktree, Entry::is_skip_worktree, Version::V3);
+    }
+
+    #[test]
+    fn intent_to_add_roundtrips_and_needs_v3() {
+        roundtrip(Entry::set_intent_to_add, Entry::is_intent_to_add, Version::V3);
+    }
+
+    #[test]
+    fn extended_flags_are_retained_while_one_of_them_is_set() {
+        let mut state = Fixture::Generated("v2_more_files").open();
+        let entry = &mut state.entries_mut()[0];
+        entry
+            .set_skip_worktree(true)
+            .set_intent_to_add(true)
+            .set_skip_worktree(false);
+        assert!(entry.is_intent_to_add());
+        assert!(entry.flags.contains(gix_index::entry::Flags::EXTENDED));
+    }
+}
@@ -103,6 +103,20 @@ fn v2_with_multiple_entries_without_eoie_ext() {
     assert_eq!(tree.name.as_bstr(), "d");
 }
 
+#[test]
+#[cfg(feature = "serde1")]
+fn state_roundtrips_through_json() {
+    let file = file("v2_more_files");
+    let json = serde_json::to_string(&*file).unwrap();
+    let state: gix_index::State = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(state.version(), file.version());
+    assert_eq!(state.object_hash(), file.object_hash());
+    assert_eq!(state.entries(), file.entries());
+    assert_eq!(state.path_backing(), file.path_backing());
+    assert!(state.tree().is_none(), "extensions aren't serialized");
+}
+
 fn find_shared_index_for(index: impl AsRef<Path>) -> PathBuf {
     let mut matches = std::fs::read_dir(index.as_ref().parent().unwrap())
         .unwrap()
@@ -335,3 +349,170 @@ fn split_index_and_regular_index_of_same_content_are_indeed_the_same() {
         assert_eq!(s.path_in(split.path_backing()), r.path_in(regular.path_backing()));
     })
 }
+
+#[test]
+fn read_header_provides_version_and_entry_count_only() {
+    for (name, version, num_entries) in [
+        ("v2", Version::V2, 1),
+        ("v2_more_files", Version::V2, 6),
+        ("v4_more_files_IEOT", Version::V4, 10),
+    ] {
+        let data = std::fs::read(crate::fixture_index_path(name)).unwrap();
+        assert_eq!(
+            gix_index::read_header(&data, gix_hash::Kind::Sha1).unwrap(),
+            (version, num_entries),
+            "{name}"
+        );
+    }
+
+    let mut data = std::fs::read(crate::fixture_index_path("v2")).unwrap();
+    assert!(matches!(
+        gix_index::read_header(&data[..12], gix_hash::Kind::Sha1),
+        Err(gix_index::decode::header::Error::Corrupt(_))
+    ));
+    data[7] = 5;
+    assert!(matches!(
+        gix_index::read_header(&data, gix_hash::Kind::Sha1),
+        Err(gix_index::decode::header::Error::UnsupportedVersion(5))
+    ));
+    data[0] = b'X';
+    assert!(matches!(
+        gix_index::read_header(&data, gix_hash::Kind::Sha1),
+        Err(gix_index::decode::header::Error::Corrupt(_))
+    ));
+}
+
+#[test]
+fn optional_extensions_can_be_skipped_while_entries_are_still_read() {
+    use gix_index::decode::{Extensions, Options};
+    let expected = file("v2");
+    assert!(expected.tree().is_some());
+    for thread_limit in [Some(1), None] {
+        for extensions in [
+            Extensions::None,
+            Extensions::Given {
+                tree_cache: false,
+                resolve_undo: true,
+                untracked_cache: true,
+                fs_monitor: true,
+            },
+        ] {
+            let actual = file_opt(
+                "v2",
+                Options {
+                    extensions,
+                    thread_limit,
+                    min_extension_block_in_bytes_for_threading: 0,
+                    ..Default::default()
+                },
+            );
+            assert!(actual.tree().is_none(), "the tree extension was skipped");
+            assert_eq!(actual.entries(), expected.entries());
+        }
+    }
+
+    let untracked = Options {
+        extensions: Extensions::Given {
+            tree_cache: true,
+            resolve_undo: false,
+            untracked_cache: false,
+            fs_monitor: false,
+        },
+        ..Default::default()
+    };
+    let file = gix_index::File::at(loose_file_path("UNTR"), gix_hash::Kind::Sha1, untracked).unwrap();
+    assert!(file.untracked().is_none());
+    assert!(loose_file("UNTR").untracked().is_some());
+}
+
+#[test]
+fn trailing_bytes_can_be_ignored_and_checksums_can_be_verified_or_tolerated() {
+    use filetime::FileTime;
+    use gix_index::{
+        decode::{self, Checksum, Options, Trailing, Warning},
+        State,
+    };
+    let from_bytes = |data: &[u8], options| State::from_bytes(data, FileTime::zero(), gix_hash::Kind::Sha1, options);
+
+    for path in [loose_file_path("REUC"), crate::fixture_index_path("v2")] {
+        let data = std::fs::read(path).unwrap();
+        let (expected, expected_checksum) = from_bytes(&data, Default::default()).unwrap();
+
+        let mut with_trailing_bytes = data.clone();
+        with_trailing_bytes.extend_from_slice(b"garbage after the checksum");
+        assert!(
+            from_bytes(&with_trailing_bytes, Default::default()).is_err(),
+            "like git, we don't tolerate trailing bytes by default"
+        );
+
+        let options = Options {
+            trailing_bytes: Trailing::Ignore,
+            verify_checksum: Checksum::Fail,
+            ..Default::default()
+        };
+        let (actual, checksum, warnings) =
+            State::from_bytes_with_warnings(&with_trailing_bytes, FileTime::zero(), gix_hash::Kind::Sha1, options)
+                .unwrap();
+        assert_eq!(warnings, vec![Warning::TrailingBytes { len: 26 }]);
+        assert_eq!(checksum, expected_checksum, "the checksum was found and verified");
+        assert_eq!(actual.entries(), expected.entries());
+        assert_eq!(actual.tree(), expected.tree());
+        assert_eq!(actual.resolve_undo().is_some(), expected.resolve_undo().is_some());
+
+        let (_, _, warnings) =
+            State::from_bytes_with_warnings(&data, FileTime::zero(), gix_hash::Kind::Sha1, options).unwrap();
+        assert!(warnings.is_empty(), "intact files decode without warnings");
+
+        let mut corrupt = data.clone();
+        corrupt[12] ^= 1; // the stat information of the first entry
+        assert!(
+            from_bytes(&corrupt, Default::default()).is_ok(),
+            "checksums aren't verified by default"
+        );
+        assert!(matches!(
+            from_bytes(&corrupt, options),
+            Err(decode::Error::ContentChecksumMismatch { expected, .. }) if expected == expected_checksum
+        ));
+
+        let tolerant = Options {
+            verify_checksum: Checksum::Warn,
+            ..options
+        };
+        let (actual, checksum, warnings) =
+            State::from_bytes_with_warnings(&corrupt, FileTime::zero(), gix_hash::Kind::Sha1, tolerant).unwrap();
+        assert_eq!(checksum, expected_checksum);
+        assert!(
+            matches!(warnings.as_slice(), [Warning::ChecksumMismatch { actual, expected }] if *expected == expected_checksum && *actual != expected_checksum),
+            "the mismatch is reported, but doesn't prevent decoding"
+        );
+        assert_eq!(actual.entries().len(), expected.entries().len());
+        assert_eq!(
+            actual.entries()[1..],
+            expected.entries()[1..],
+            "all entries but the corrupted one are unaffected"
+        );
+
+        let mut corrupt_trailer = with_trailing_bytes.clone();
+        corrupt_trailer[data.len() - 1] ^= 1;
+        let (actual, checksum, warnings) =
+            State::from_bytes_with_warnings(&corrupt_trailer, FileTime::zero(), gix_hash::Kind::Sha1, tolerant)
+                .unwrap();
+        assert_ne!(checksum, expected_checksum, "the checksum is returned as stored");
+        assert_eq!(
+            warnings,
+            vec![
+                Warning::TrailingBytes { len: 26 },
+                Warning::ChecksumMismatch {
+                    actual: expected_checksum,
+                    expected: checksum,
+                }
+            ],
+            "a corrupted trailer is detected as well"
+        );
+        assert_eq!(actual.entries(), expected.entries(), "entries are recovered in full");
+        assert!(matches!(
+            from_bytes(&corrupt_trailer, options),
+            Err(decode::Error::ContentChecksumMismatch { .. })
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Deref;
+
 use filetime::FileTime;
 use gix_index::{entry, extension, verify::extensions::no_find, write, write::Options, State, Version};
 
@@ -77,6 +79,38 @@ fn roundtrips_sparse_index() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn entries_are_padded_with_nulls_to_a_multiple_of_eight_bytes_like_git() -> crate::Result {
+    let state = Loose("very-long-path").open();
+    let entry = &state.entries()[0];
+    let path_len = entry.path(&state).len();
+    assert!(
+        path_len > entry::Flags::PATH_LEN.bits() as usize,
+        "the path is too long to have its length stored in the flags"
+    );
+    assert!(!entry.flags.contains(entry::Flags::EXTENDED));
+
+    let mut buf = Vec::new();
+    state.write_to(&mut buf, only_tree_ext())?;
+
+    const HEADER_LEN: usize = 12;
+    const FIXED_ENTRY_LEN: usize = 62;
+    let entry_len = (FIXED_ENTRY_LEN + path_len + 8) & !7;
+    let padding = &buf[HEADER_LEN + FIXED_ENTRY_LEN + path_len..HEADER_LEN + entry_len];
+    assert_eq!(
+        padding.len(),
+        8 - (FIXED_ENTRY_LEN + path_len) % 8,
+        "between 1 and 8 null bytes, including the terminating one"
+    );
+    assert!(padding.iter().all(|b| *b == 0));
+    assert_ne!(
+        buf[HEADER_LEN + entry_len],
+        0,
+        "the next entry or extension follows right after the padding"
+    );
+    Ok(())
+}
+
 #[test]
 fn state_comparisons_with_various_extension_configurations() {
     for fixture in [
@@ -168,6 +202,49 @@ fn remove_flag_is_respected() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn write_to_path_through_a_lock_file() -> crate::Result {
+    let expected = Generated("v4_more_files_IEOT").open();
+    let dir = gix_testtools::tempfile::tempdir()?;
+    let index_path = dir.path().join("index");
+    let mut index = gix_index::File::from_state(expected.deref().clone(), &index_path);
+    assert_eq!(index.checksum(), None);
+
+    index.write(Default::default())?;
+    assert!(
+        !dir.path().join("index.lock").exists(),
+        "the lock file was moved into place"
+    );
+    assert!(index.checksum().is_some(), "the checksum of the written file is stored");
+
+    let actual = gix_index::File::at(&index_path, gix_hash::Kind::Sha1, Default::default())?;
+    actual.verify_integrity()?;
+    assert_eq!(actual.checksum(), index.checksum());
+    assert_eq!(actual.entries(), expected.entries());
+    let mut expected_bytes = Vec::new();
+    expected.write_to(&mut expected_bytes, Default::default())?;
+    assert_eq!(
+        std::fs::read(&index_path)?,
+        expected_bytes,
+        "the file on disk is the same as what would be written to memory"
+    );
+
+    std::fs::write(dir.path().join("index.lock"), b"held by someone else")?;
+    index.entries_mut()[0].flags.insert(entry::Flags::REMOVE);
+    assert!(matches!(
+        index.write(Default::default()),
+        Err(gix_index::file::write::Error::Locked { resource_path }) if resource_path == index_path
+    ));
+    assert_eq!(
+        gix_index::File::at(&index_path, gix_hash::Kind::Sha1, Default::default())?
+            .entries()
+            .len(),
+        expected.entries().len(),
+        "the index is left untouched if it's locked"
+    );
+    Ok(())
+}
+
 fn compare_states_against_baseline(
     actual: &State,
     actual_version: Version,
@@ -1,20 +1,22 @@
+use std::borrow::Cow;
+
 use bstr::BStr;
 
-use crate::Entry;
+use crate::{Entry, OwnedEntry};
 
 /// Access
 impl<'a> Entry<'a> {
     /// The name to map to.
-    pub fn new_name(&self) -> Option<&'a BStr> {
-        self.new_name
+    pub fn new_name(&self) -> Option<&BStr> {
+        self.new_name.as_deref()
     }
     /// The email map to.
     pub fn new_email(&self) -> Option<&'a BStr> {
         self.new_email
     }
     /// The name to look for and replace.
-    pub fn old_name(&self) -> Option<&'a BStr> {
-        self.old_name
+    pub fn old_name(&self) -> Option<&BStr> {
+        self.old_name.as_deref()
     }
     /// The email to look for and replace.
     pub fn old_email(&self) -> &'a BStr {
@@ -22,6 +24,38 @@ impl<'a> Entry<'a> {
     }
 }
 
+/// Conversion
+impl<'a> Entry<'a> {
+    /// Copy all names and emails into a new instance which doesn't borrow from the parsed buffer anymore.
+    pub fn to_owned(&self) -> OwnedEntry {
+        OwnedEntry {
+            new_name: self.new_name.as_deref().map(ToOwned::to_owned),
+            new_email: self.new_email.map(ToOwned::to_owned),
+            old_name: self.old_name.as_deref().map(ToOwned::to_owned),
+            old_email: self.old_email.to_owned(),
+        }
+    }
+}
+
+impl From<Entry<'_>> for OwnedEntry {
+    fn from(entry: Entry<'_>) -> Self {
+        entry.to_owned()
+    }
+}
+
+/// Access
+impl OwnedEntry {
+    /// Borrow this instance as [`Entry`], for example to pass it to [`Snapshot::new()`][crate::Snapshot::new()].
+    pub fn to_ref(&self) -> Entry<'_> {
+        Entry {
+            new_name: self.new_name.as_ref().map(|b| Cow::Borrowed(b.as_ref())),
+            new_email: self.new_email.as_ref().map(|b| b.as_ref()),
+            old_name: self.old_name.as_ref().map(|b| Cow::Borrowed(b.as_ref())),
+            old_email: self.old_email.as_ref(),
+        }
+    }
+}
+
 /// Constructors indicating what kind of mapping is created.
 ///
 /// Only these combinations of values are valid.
@@ -29,7 +63,7 @@ impl<'a> Entry<'a> {
 impl<'a> Entry<'a> {
     pub fn change_name_by_email(proper_name: impl Into<&'a BStr>, commit_email: impl Into<&'a BStr>) -> Self {
         Entry {
-            new_name: Some(proper_name.into()),
+            new_name: Some(Cow::Borrowed(proper_name.into())),
             old_email: commit_email.into(),
             ..Default::default()
         }
@@ -47,7 +81,7 @@ impl<'a> Entry<'a> {
         commit_email: impl Into<&'a BStr>,
     ) -> Self {
         Entry {
-            new_name: Some(proper_name.into()),
+            new_name: Some(Cow::Borrowed(proper_name.into())),
             new_email: Some(proper_email.into()),
             old_email: commit_email.into(),
             ..Default::default()
@@ -61,9 +95,9 @@ impl<'a> Entry<'a> {
         commit_email: impl Into<&'a BStr>,
     ) -> Self {
         Entry {
-            new_name: Some(proper_name.into()),
+            new_name: Some(Cow::Borrowed(proper_name.into())),
             new_email: Some(proper_email.into()),
-            old_name: Some(commit_name.into()),
+            old_name: Some(Cow::Borrowed(commit_name.into())),
             old_email: commit_email.into(),
         }
     }
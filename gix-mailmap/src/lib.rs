@@ -9,7 +9,9 @@
 #![deny(missing_docs, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 
-use bstr::BStr;
+use std::borrow::Cow;
+
+use bstr::{BStr, BString};
 
 ///
 pub mod parse;
@@ -18,15 +20,44 @@ pub mod parse;
 ///
 /// Errors may occur per line, but it's up to the caller to stop iteration when
 /// one is encountered.
+///
+/// Names may be enclosed in double quotes to allow them to contain `<` or `>`, like in `"Weird <Name>" <email>`.
+/// The content between the quotes is taken literally, except for backslash-escapes like `\"` or `\\`, which are unescaped.
+///
+/// Just like in git, a commit name is only valid if it's followed by a commit email, as commits are always matched by email.
 pub fn parse(buf: &[u8]) -> parse::Lines<'_> {
     parse::Lines::new(buf)
 }
 
+/// Similar to [parse()], but will also return the [byte ranges][parse::Spans] of all names and emails of each entry
+/// within `buf`.
+///
+/// Errors are returned as [`parse::SpannedError`], which also carries the byte range of the offending line or fragment.
+pub fn parse_with_spans(buf: &[u8]) -> parse::LinesWithSpans<'_> {
+    parse::LinesWithSpans::new(buf)
+}
+
 /// Similar to [parse()], but will skip all lines that didn't parse correctly, silently squelching all errors.
 pub fn parse_ignore_errors(buf: &[u8]) -> impl Iterator<Item = Entry<'_>> {
     parse(buf).filter_map(Result::ok)
 }
 
+/// Similar to [parse()], but will parse all lines and collect all entries and all errors, in order of occurrence.
+///
+/// This is useful to report all problems of a mailmap file at once, similar to how git warns about each malformed
+/// line but continues to parse the remainder.
+pub fn parse_lenient(buf: &[u8]) -> (Vec<Entry<'_>>, Vec<parse::Error>) {
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    for res in parse(buf) {
+        match res {
+            Ok(entry) => entries.push(entry),
+            Err(err) => errors.push(err),
+        }
+    }
+    (entries, errors)
+}
+
 mod entry;
 
 ///
@@ -36,6 +67,13 @@ pub mod snapshot;
 /// optionally name to find mappings to new names and/or emails.
 ///
 /// The memory layout is efficient, even though lots of small allocations are performed to store strings of emails and names.
+///
+/// Emails are compared case-insensitively, so `Foo@Example.COM` in a mailmap matches a signature with `foo@example.com`.
+/// Just like in git, this also applies to the optional old name used for lookup, so `Jane` matches a signature named `jane`.
+/// Case folding is limited to ASCII and only performed if both strings are valid UTF-8; otherwise they are compared byte by byte.
+///
+/// Names aren't otherwise touched, i.e. new names are returned exactly as written in the mailmap, and unmapped names
+/// keep the case they have in the signature.
 #[derive(Default, Clone)]
 pub struct Snapshot {
     /// Sorted by `old_email`
@@ -48,16 +86,36 @@ pub struct Snapshot {
 /// Optionally, `old_name` is also used for lookup.
 ///
 /// Typically created by [parse()].
-#[derive(PartialEq, Eq, Debug, Hash, Ord, PartialOrd, Clone, Copy, Default)]
+///
+/// Names are borrowed from the parsed buffer unless they were quoted and contained escapes like `\"`, which are unescaped.
+#[derive(PartialEq, Eq, Debug, Hash, Ord, PartialOrd, Clone, Default)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entry<'a> {
     #[cfg_attr(feature = "serde1", serde(borrow))]
     /// The name to map to.
-    pub(crate) new_name: Option<&'a BStr>,
+    pub(crate) new_name: Option<Cow<'a, BStr>>,
     /// The email map to.
+    #[cfg_attr(feature = "serde1", serde(borrow))]
     pub(crate) new_email: Option<&'a BStr>,
     /// The name to look for and replace.
-    pub(crate) old_name: Option<&'a BStr>,
+    #[cfg_attr(feature = "serde1", serde(borrow))]
+    pub(crate) old_name: Option<Cow<'a, BStr>>,
     /// The email to look for and replace.
     pub(crate) old_email: &'a BStr,
 }
+
+/// An owned version of [`Entry`], which doesn't borrow from the buffer it was parsed from.
+///
+/// Typically created by [`Entry::to_owned()`].
+#[derive(PartialEq, Eq, Debug, Hash, Ord, PartialOrd, Clone, Default)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedEntry {
+    /// The name to map to.
+    pub(crate) new_name: Option<BString>,
+    /// The email map to.
+    pub(crate) new_email: Option<BString>,
+    /// The name to look for and replace.
+    pub(crate) old_name: Option<BString>,
+    /// The email to look for and replace.
+    pub(crate) old_email: BString,
+}
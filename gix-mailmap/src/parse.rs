@@ -1,4 +1,6 @@
 mod error {
+    use std::ops::Range;
+
     use bstr::BString;
     use quick_error::quick_error;
 
@@ -10,29 +12,85 @@ mod error {
             UnconsumedInput { line_number: usize, line: BString } {
                 display("Line {} has too many names or emails, or none at all: {}", line_number, line)
             }
-            Malformed { line_number: usize, line: BString, message: String } {
-                display("{}: {:?}: {}", line_number, line, message)
+            MissingEmail { line_number: usize, name: BString } {
+                display("Line {} has a name but no email: {:?}", line_number, name)
+            }
+            MissingClosingQuote { line_number: usize, fragment: BString } {
+                display("Line {} is missing the closing quote '\"' of a name: {:?}", line_number, fragment)
+            }
+            MissingClosingBracket { line_number: usize, fragment: BString } {
+                display("Line {} is missing the closing bracket '>' in email: {:?}", line_number, fragment)
+            }
+            EmptyEmail { line_number: usize, fragment: BString } {
+                display("Line {} has an email enclosed in brackets that is empty: {:?}", line_number, fragment)
+            }
+            MissingMappingTarget { line_number: usize, line: BString } {
+                display("Line {} has an email without a name or email to map to: {:?}", line_number, line)
+            }
+            MissingCommitEmail { line_number: usize, name: BString } {
+                display("Line {} has the commit name {:?} without an email, but commits are always matched by email, optionally along with their name", line_number, name)
             }
         }
     }
+
+    /// An [`Error`] along with the byte range of the offending line or fragment within the buffer that was parsed.
+    ///
+    /// Returned by [`parse_with_spans()`][crate::parse_with_spans()].
+    #[derive(Debug)]
+    pub struct SpannedError {
+        /// The actual error.
+        pub error: Error,
+        /// The byte range of the offending line or fragment.
+        pub span: Range<usize>,
+    }
+
+    impl std::fmt::Display for SpannedError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.error.fmt(f)
+        }
+    }
+
+    impl std::error::Error for SpannedError {}
+
+    impl From<SpannedError> for Error {
+        fn from(err: SpannedError) -> Self {
+            err.error
+        }
+    }
 }
 
-use bstr::{BStr, ByteSlice};
-pub use error::Error;
+use std::{borrow::Cow, ops::Range};
+
+use bstr::{BStr, BString, ByteSlice};
+pub use error::{Error, SpannedError};
 
 use crate::Entry;
 
+/// The byte ranges of all fields of an [`Entry`] within the buffer it was parsed from.
+///
+/// Typically obtained from [`parse_with_spans()`][crate::parse_with_spans()], and useful for tooling that wants to
+/// point at the exact location of a name or email.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Default)]
+pub struct Spans {
+    /// The range of [`Entry::new_name()`], if present.
+    pub new_name: Option<Range<usize>>,
+    /// The range of [`Entry::new_email()`], if present.
+    pub new_email: Option<Range<usize>>,
+    /// The range of [`Entry::old_name()`], if present.
+    pub old_name: Option<Range<usize>>,
+    /// The range of [`Entry::old_email()`].
+    pub old_email: Range<usize>,
+}
+
 /// An iterator to parse mailmap lines on-demand.
 pub struct Lines<'a> {
-    lines: bstr::Lines<'a>,
-    line_no: usize,
+    inner: LinesWithSpans<'a>,
 }
 
 impl<'a> Lines<'a> {
     pub(crate) fn new(input: &'a [u8]) -> Self {
         Lines {
-            lines: input.as_bstr().lines(),
-            line_no: 0,
+            inner: LinesWithSpans::new(input),
         }
     }
 }
@@ -40,78 +98,328 @@ impl<'a> Lines<'a> {
 impl<'a> Iterator for Lines<'a> {
     type Item = Result<Entry<'a>, Error>;
 
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|res| res.map(|(entry, _spans)| entry).map_err(Into::into))
+    }
+}
+
+/// An iterator to parse mailmap lines on-demand, along with the [byte ranges][Spans] of each of their fields.
+///
+/// Errors carry the byte range of the offending line or fragment as well.
+pub struct LinesWithSpans<'a> {
+    lines: bstr::LinesWithTerminator<'a>,
+    line_no: usize,
+    offset: usize,
+}
+
+impl<'a> LinesWithSpans<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        LinesWithSpans {
+            lines: input.as_bstr().lines_with_terminator(),
+            line_no: 0,
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for LinesWithSpans<'a> {
+    type Item = Result<(Entry<'a>, Spans), SpannedError>;
+
     fn next(&mut self) -> Option<Self::Item> {
         for line in self.lines.by_ref() {
             self.line_no += 1;
+            let line_start = self.offset;
+            self.offset += line.len();
             match line.first() {
-                None => continue,
                 Some(b) if *b == b'#' => continue,
-                Some(_) => {}
+                _ => {}
             }
-            let line = line.trim();
+            let (line, offset) = trim(line, line_start);
             if line.is_empty() {
                 continue;
             }
-            return parse_line(line.into(), self.line_no).into();
+            return parse_line(line, offset, self.line_no).into();
         }
         None
     }
 }
 
-fn parse_line(line: &BStr, line_number: usize) -> Result<Entry<'_>, Error> {
-    let (name1, email1, rest) = parse_name_and_email(line, line_number)?;
-    let (name2, email2, rest) = parse_name_and_email(rest, line_number)?;
-    if !rest.trim().is_empty() {
-        return Err(Error::UnconsumedInput {
-            line_number,
-            line: line.into(),
-        });
-    }
-    Ok(match (name1, email1, name2, email2) {
-        (Some(proper_name), Some(commit_email), None, None) => Entry::change_name_by_email(proper_name, commit_email),
-        (None, Some(proper_email), None, Some(commit_email)) => {
-            Entry::change_email_by_email(proper_email, commit_email)
+/// Remove a trailing comment from `line`, which starts with a `#` that is neither enclosed in `<>` nor in a quoted name,
+/// and that follows at least one email. This way, names may still start with or contain `#`.
+///
+/// Just like in [`parse_name_and_email()`], a `"` only starts a quoted name if it's the first byte of a name, so it
+/// may be part of unquoted names like `O"Brien`.
+fn strip_trailing_comment(line: &BStr) -> &BStr {
+    let mut in_email = false;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut seen_email = false;
+    let mut at_name_start = true;
+    for (pos, b) in line.iter().enumerate() {
+        match b {
+            _ if escaped => escaped = false,
+            b'\\' if in_quotes => escaped = true,
+            b'"' if in_quotes => in_quotes = false,
+            _ if in_quotes => {}
+            b'"' if at_name_start => in_quotes = true,
+            b'<' => in_email = true,
+            b'>' if in_email => {
+                in_email = false;
+                seen_email = true;
+                at_name_start = true;
+                continue;
+            }
+            b'#' if !in_email && seen_email => return line[..pos].trim_end().as_bstr(),
+            _ => {}
         }
-        (Some(proper_name), Some(proper_email), None, Some(commit_email)) => {
-            Entry::change_name_and_email_by_email(proper_name, proper_email, commit_email)
+        if !b.is_ascii_whitespace() {
+            at_name_start = false;
         }
-        (Some(proper_name), Some(proper_email), Some(commit_name), Some(commit_email)) => {
-            Entry::change_name_and_email_by_name_and_email(proper_name, proper_email, commit_name, commit_email)
+    }
+    line
+}
+
+/// A name or email along with its byte range within the parsed buffer.
+type Field<'a, T = &'a BStr> = (T, Range<usize>);
+
+/// An optional name and email, followed by the remaining input along with its offset in the parsed buffer.
+type NameAndEmail<'a> = (Option<Field<'a, Cow<'a, BStr>>>, Option<Field<'a>>, &'a BStr, usize);
+
+/// Trim whitespace around `input` which starts at `offset` in the parsed buffer, returning the trimmed
+/// slice along with its own offset.
+fn trim(input: &[u8], offset: usize) -> (&BStr, usize) {
+    let trimmed_start = input.trim_start();
+    (
+        trimmed_start.trim_end().as_bstr(),
+        offset + input.len() - trimmed_start.len(),
+    )
+}
+
+fn span(input: &[u8], offset: usize) -> Range<usize> {
+    offset..offset + input.len()
+}
+
+/// Parse `line`, which starts at `offset` in the parsed buffer.
+fn parse_line(line: &BStr, offset: usize, line_number: usize) -> Result<(Entry<'_>, Spans), SpannedError> {
+    let line = strip_trailing_comment(line);
+    let (name1, email1, rest, rest_offset) = parse_name_and_email(line, offset, line_number)?;
+    if email1.is_none() {
+        return Err(SpannedError {
+            error: Error::MissingEmail {
+                line_number,
+                name: line.into(),
+            },
+            span: span(line, offset),
+        });
+    }
+    let (name2, email2, rest, rest_offset) = parse_name_and_email(rest, rest_offset, line_number)?;
+    if email2.is_none() {
+        let (unquoted_name, unquoted_name_offset) = trim(rest, rest_offset);
+        let commit_name = name2.clone().or_else(|| {
+            (!unquoted_name.is_empty()).then(|| (unquoted_name.into(), span(unquoted_name, unquoted_name_offset)))
+        });
+        if let Some((name, name_span)) = commit_name {
+            return Err(SpannedError {
+                error: Error::MissingCommitEmail {
+                    line_number,
+                    name: name.into_owned(),
+                },
+                span: name_span,
+            });
         }
-        _ => {
-            return Err(Error::Malformed {
+    }
+    if !rest.trim().is_empty() {
+        return Err(SpannedError {
+            error: Error::UnconsumedInput {
                 line_number,
                 line: line.into(),
-                message: "Emails without a name or email to map to are invalid".into(),
+            },
+            span: span(line, offset),
+        });
+    }
+    Ok(match (name1, email1, name2, email2) {
+        (Some((proper_name, name_span)), Some((commit_email, email_span)), None, None) => (
+            Entry {
+                new_name: Some(proper_name),
+                old_email: commit_email,
+                ..Default::default()
+            },
+            Spans {
+                new_name: Some(name_span),
+                old_email: email_span,
+                ..Default::default()
+            },
+        ),
+        (None, Some((proper_email, proper_email_span)), None, Some((commit_email, commit_email_span))) => (
+            Entry {
+                new_email: Some(proper_email),
+                old_email: commit_email,
+                ..Default::default()
+            },
+            Spans {
+                new_email: Some(proper_email_span),
+                old_email: commit_email_span,
+                ..Default::default()
+            },
+        ),
+        (
+            Some((proper_name, name_span)),
+            Some((proper_email, proper_email_span)),
+            None,
+            Some((commit_email, commit_email_span)),
+        ) => (
+            Entry {
+                new_name: Some(proper_name),
+                new_email: Some(proper_email),
+                old_email: commit_email,
+                ..Default::default()
+            },
+            Spans {
+                new_name: Some(name_span),
+                new_email: Some(proper_email_span),
+                old_name: None,
+                old_email: commit_email_span,
+            },
+        ),
+        (
+            Some((proper_name, proper_name_span)),
+            Some((proper_email, proper_email_span)),
+            Some((commit_name, commit_name_span)),
+            Some((commit_email, commit_email_span)),
+        ) => (
+            Entry {
+                new_name: Some(proper_name),
+                new_email: Some(proper_email),
+                old_name: Some(commit_name),
+                old_email: commit_email,
+            },
+            Spans {
+                new_name: Some(proper_name_span),
+                new_email: Some(proper_email_span),
+                old_name: Some(commit_name_span),
+                old_email: commit_email_span,
+            },
+        ),
+        _ => {
+            return Err(SpannedError {
+                error: Error::MissingMappingTarget {
+                    line_number,
+                    line: line.into(),
+                },
+                span: span(line, offset),
             })
         }
     })
 }
 
-fn parse_name_and_email(
+/// Parse an optional name and email from `line`, which starts at `offset` in the parsed buffer, and return them along
+/// with the remaining input and its offset.
+fn parse_name_and_email(line: &BStr, offset: usize, line_number: usize) -> Result<NameAndEmail<'_>, SpannedError> {
+    let trimmed = line.trim_start();
+    if trimmed.first() != Some(&b'"') {
+        return parse_unquoted_name_and_email(line, offset, line_number);
+    }
+    let trimmed_offset = offset + line.len() - trimmed.len();
+    let quoted = &trimmed[1..];
+    let closing_quote = find_closing_quote(quoted).ok_or_else(|| SpannedError {
+        error: Error::MissingClosingQuote {
+            line_number,
+            fragment: trimmed.into(),
+        },
+        span: span(trimmed, trimmed_offset),
+    })?;
+    let name = quoted[..closing_quote].as_bstr();
+    let name_span = span(name, trimmed_offset + 1);
+    let (unquoted_name, email, rest, rest_offset) =
+        parse_unquoted_name_and_email(quoted[closing_quote + 1..].as_bstr(), name_span.end + 1, line_number)?;
+    if unquoted_name.is_some() {
+        return Err(SpannedError {
+            error: Error::UnconsumedInput {
+                line_number,
+                line: line.into(),
+            },
+            span: span(line, offset),
+        });
+    }
+    Ok((
+        (!name.is_empty()).then(|| (unescape(name), name_span)),
+        email,
+        rest,
+        rest_offset,
+    ))
+}
+
+/// Remove the backslashes of all escapes in the quoted `name`, so `\"` becomes `"` and `\\` becomes `\`.
+fn unescape(name: &BStr) -> Cow<'_, BStr> {
+    if name.find_byte(b'\\').is_none() {
+        return name.into();
+    }
+    let mut out = BString::default();
+    let mut escaped = false;
+    for b in name.iter() {
+        match b {
+            b'\\' if !escaped => escaped = true,
+            _ => {
+                escaped = false;
+                out.push(*b);
+            }
+        }
+    }
+    out.into()
+}
+
+/// Return the position of the first `"` in `quoted` that isn't escaped with a backslash.
+fn find_closing_quote(quoted: &[u8]) -> Option<usize> {
+    let mut escaped = false;
+    for (pos, b) in quoted.iter().enumerate() {
+        match b {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'"' => return Some(pos),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_unquoted_name_and_email(
     line: &BStr,
+    offset: usize,
     line_number: usize,
-) -> Result<(Option<&'_ BStr>, Option<&'_ BStr>, &'_ BStr), Error> {
+) -> Result<NameAndEmail<'_>, SpannedError> {
     match line.find_byte(b'<') {
         Some(start_bracket) => {
             let email = &line[start_bracket + 1..];
-            let closing_bracket = email.find_byte(b'>').ok_or_else(|| Error::Malformed {
-                line_number,
-                line: line.into(),
-                message: "Missing closing bracket '>' in email".into(),
+            let closing_bracket = email.find_byte(b'>').ok_or_else(|| SpannedError {
+                error: Error::MissingClosingBracket {
+                    line_number,
+                    fragment: line[start_bracket..].into(),
+                },
+                span: span(&line[start_bracket..], offset + start_bracket),
             })?;
-            let email = email[..closing_bracket].trim().as_bstr();
+            let (email, email_offset) = trim(&email[..closing_bracket], offset + start_bracket + 1);
             if email.is_empty() {
-                return Err(Error::Malformed {
-                    line_number,
-                    line: line.into(),
-                    message: "Email must not be empty".into(),
+                let fragment = &line[start_bracket..][..closing_bracket + 2];
+                return Err(SpannedError {
+                    error: Error::EmptyEmail {
+                        line_number,
+                        fragment: fragment.into(),
+                    },
+                    span: span(fragment, offset + start_bracket),
                 });
             }
-            let name = line[..start_bracket].trim().as_bstr();
-            let rest = line[start_bracket + closing_bracket + 2..].as_bstr();
-            Ok(((!name.is_empty()).then_some(name), Some(email), rest))
+            let (name, name_offset) = trim(&line[..start_bracket], offset);
+            let rest_start = start_bracket + closing_bracket + 2;
+            let rest = line[rest_start..].as_bstr();
+            Ok((
+                (!name.is_empty()).then(|| (name.into(), span(name, name_offset))),
+                Some((email, span(email, email_offset))),
+                rest,
+                offset + rest_start,
+            ))
         }
-        None => Ok((None, None, line)),
+        None => Ok((None, None, line, offset)),
     }
 }
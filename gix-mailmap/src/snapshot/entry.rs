@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use bstr::BString;
 
 use crate::snapshot::util::{EncodedString, EncodedStringRef};
@@ -29,14 +31,14 @@ impl EmailEntry {
         }: crate::Entry<'_>,
     ) {
         let new_email = new_email.map(ToOwned::to_owned);
-        let new_name = new_name.map(ToOwned::to_owned);
+        let new_name = new_name.map(Cow::into_owned);
         match old_name {
             None => {
                 self.new_email = new_email;
                 self.new_name = new_name;
             }
             Some(old_name) => {
-                let old_name: EncodedStringRef<'_> = old_name.into();
+                let old_name: EncodedStringRef<'_> = old_name.as_ref().into();
                 match self
                     .entries_by_old_name
                     .binary_search_by(|e| e.old_name.cmp_ref(old_name))
@@ -69,14 +71,14 @@ impl<'a> From<crate::Entry<'a>> for EmailEntry {
             old_email,
         }: crate::Entry<'a>,
     ) -> Self {
-        let mut new_name = new_name.map(ToOwned::to_owned);
+        let mut new_name = new_name.map(Cow::into_owned);
         let mut new_email = new_email.map(ToOwned::to_owned);
         let entries_by_old_name = old_name
             .map(|name| {
                 vec![NameEntry {
                     new_name: new_name.take(),
                     new_email: new_email.take(),
-                    old_name: name.into(),
+                    old_name: name.as_ref().into(),
                 }]
             })
             .unwrap_or_default();
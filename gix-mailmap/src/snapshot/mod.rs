@@ -60,7 +60,7 @@ impl Snapshot {
         for entry in &self.entries_by_old_email {
             if entry.new_email.is_some() || entry.new_name.is_some() {
                 out.push(crate::Entry {
-                    new_name: entry.new_name.as_ref().map(|b| b.as_bstr()),
+                    new_name: entry.new_name.as_ref().map(|b| b.as_bstr().into()),
                     new_email: entry.new_email.as_ref().map(|b| b.as_bstr()),
                     old_name: None,
                     old_email: entry.old_email.as_bstr(),
@@ -69,9 +69,9 @@ impl Snapshot {
 
             for name_entry in &entry.entries_by_old_name {
                 out.push(crate::Entry {
-                    new_name: name_entry.new_name.as_ref().map(|b| b.as_bstr()),
+                    new_name: name_entry.new_name.as_ref().map(|b| b.as_bstr().into()),
                     new_email: name_entry.new_email.as_ref().map(|b| b.as_bstr()),
-                    old_name: name_entry.old_name.as_bstr().into(),
+                    old_name: Some(name_entry.old_name.as_bstr().into()),
                     old_email: entry.old_email.as_bstr(),
                 });
             }
@@ -133,6 +133,22 @@ impl Snapshot {
         self.try_resolve(signature).unwrap_or_else(|| signature.to_owned())
     }
 
+    /// Like [`try_resolve_ref()`][Snapshot::try_resolve_ref()], but always returns a signature whose mapped name and/or email
+    /// are borrowed from this instance, while all other fields are taken from `signature`.
+    ///
+    /// The most specific mapping wins, that is a match by name and email is preferred over a match by email only.
+    /// If no mapping was found, `signature` is returned unchanged. This method never allocates.
+    pub fn resolve_ref<'a>(&'a self, signature: gix_actor::SignatureRef<'a>) -> gix_actor::SignatureRef<'a> {
+        match self.try_resolve_ref(signature) {
+            Some(new) => SignatureRef {
+                name: new.name.unwrap_or(signature.name),
+                email: new.email.unwrap_or(signature.email),
+                time: signature.time,
+            },
+            None => signature,
+        }
+    }
+
     /// Like [`try_resolve()`][Snapshot::try_resolve()], but always returns a special copy-on-write signature, which contains
     /// changed names or emails as `Cow::Owned`, or `Cow::Borrowed` if no mapping was found.
     pub fn resolve_cow<'a>(&self, signature: gix_actor::SignatureRef<'a>) -> Signature<'a> {
@@ -8,10 +8,32 @@ fn line_numbers_are_counted_correctly_in_errors() {
     assert_eq!(actual.len(), 2);
 
     let err = actual.next().expect("two items left").unwrap_err();
-    assert!(matches!(err, parse::Error::Malformed { line_number: 3, .. }));
+    assert!(matches!(
+        err,
+        parse::Error::MissingClosingBracket { line_number: 3, .. }
+    ));
 
     let err = actual.next().expect("one item left").unwrap_err();
-    assert!(matches!(err, parse::Error::UnconsumedInput { line_number: 5, .. }));
+    assert!(matches!(err, parse::Error::MissingEmail { line_number: 5, .. }));
+}
+
+#[test]
+fn lenient_parsing_collects_all_entries_and_errors() {
+    let (entries, errors) =
+        gix_mailmap::parse_lenient(b"a <a@example.com>\n<missing\nb <b-new> <b-old>\njust a name\n");
+    assert_eq!(
+        entries,
+        vec![
+            Entry::change_name_by_email("a", "a@example.com"),
+            Entry::change_name_and_email_by_email("b", "b-new", "b-old")
+        ]
+    );
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        errors[0],
+        parse::Error::MissingClosingBracket { line_number: 2, .. }
+    ));
+    assert!(matches!(errors[1], parse::Error::MissingEmail { line_number: 4, .. }));
 }
 
 #[test]
@@ -46,6 +68,37 @@ fn empty_lines_and_comments_are_ignored() {
     );
 }
 
+#[test]
+fn comments_after_mappings_are_ignored() {
+    assert_eq!(
+        line("Proper <a@b> Commit <c@d> # note"),
+        Entry::change_name_and_email_by_name_and_email("Proper", "a@b", "Commit", "c@d")
+    );
+    assert_eq!(
+        line("Proper <a@b> <c@d>#note"),
+        Entry::change_name_and_email_by_email("Proper", "a@b", "c@d")
+    );
+    assert_eq!(
+        line("proper name <commit-email> \t # a note with <brackets>"),
+        Entry::change_name_by_email("proper name", "commit-email"),
+        "name-only forms can have comments as well"
+    );
+    assert_eq!(
+        line(r#"O"Brien <a@b> # note"#),
+        Entry::change_name_by_email(r#"O"Brien"#, "a@b"),
+        "quotes within unquoted names don't start a quoted name"
+    );
+    assert_eq!(
+        line(r#"Proper <a@b> Com"mit <c@d> # note "with quotes""#),
+        Entry::change_name_and_email_by_name_and_email("Proper", "a@b", r#"Com"mit"#, "c@d")
+    );
+    assert_eq!(
+        line("proper name <commit#email> # note"),
+        Entry::change_name_by_email("proper name", "commit#email"),
+        "hashes within emails don't start a comment"
+    );
+}
+
 #[test]
 fn windows_and_unix_line_endings_are_supported() {
     let actual = gix_mailmap::parse(b"a <a@example.com>\n<b-new><b-old>\r\nc <c@example.com>")
@@ -81,10 +134,66 @@ fn valid_entries() {
     );
 }
 
+#[test]
+fn quoted_names_may_contain_brackets() {
+    assert_eq!(
+        line(r#""Weird <Name>" <email>"#),
+        Entry::change_name_by_email("Weird <Name>", "email")
+    );
+    assert_eq!(
+        line(r#"  "proper <name>" <proper email> "commit >name<" <commit-email> # comment"#),
+        Entry::change_name_and_email_by_name_and_email(
+            "proper <name>",
+            "proper email",
+            "commit >name<",
+            "commit-email"
+        )
+    );
+    assert_eq!(
+        line(r#""with \"escaped\" <quotes> \\o/"   <email>"#),
+        Entry::change_name_by_email(r#"with "escaped" <quotes> \o/"#, "email"),
+        "escapes are removed"
+    );
+    assert_eq!(
+        line(r#""\"Nick\" Name" <proper email> "\"Nick\"" <commit-email>"#),
+        Entry::change_name_and_email_by_name_and_email(r#""Nick" Name"#, "proper email", r#""Nick""#, "commit-email"),
+        "commit names are unescaped as well"
+    );
+    assert_eq!(
+        line(r##"proper <proper email> "nick # 2" <commit-email>"##),
+        Entry::change_name_and_email_by_name_and_email("proper", "proper email", "nick # 2", "commit-email"),
+        "hashes in quoted names don't start a comment"
+    );
+}
+
+#[test]
+fn error_if_quoted_name_is_malformed() {
+    assert!(matches!(
+        try_line(r#""unclosed <email>"#),
+        Err(parse::Error::MissingClosingQuote { line_number: 1, .. })
+    ));
+    assert!(matches!(
+        try_line(r#""quoted" unquoted <email>"#),
+        Err(parse::Error::UnconsumedInput { line_number: 1, .. })
+    ));
+    assert!(matches!(
+        try_line(r#""just a quoted name""#),
+        Err(parse::Error::MissingEmail { line_number: 1, .. })
+    ));
+}
+
 #[test]
 fn error_if_there_is_just_a_name() {
     assert!(matches!(
         try_line("just a name"),
+        Err(parse::Error::MissingEmail { line_number: 1, name, .. }) if name == "just a name"
+    ));
+}
+
+#[test]
+fn error_if_there_is_too_much_input() {
+    assert!(matches!(
+        try_line("proper name <proper email> commit name <commit-email> trailing"),
         Err(parse::Error::UnconsumedInput { line_number: 1, .. })
     ));
 }
@@ -93,12 +202,40 @@ fn error_if_there_is_just_a_name() {
 fn error_if_there_is_just_an_email() {
     assert!(matches!(
         try_line("<email>"),
-        Err(parse::Error::Malformed { line_number: 1, .. })
+        Err(parse::Error::MissingMappingTarget { line_number: 1, .. })
     ));
 
     assert!(matches!(
         try_line("   \t  <email>"),
-        Err(parse::Error::Malformed { line_number: 1, .. })
+        Err(parse::Error::MissingMappingTarget { line_number: 1, .. })
+    ));
+}
+
+#[test]
+fn error_if_the_commit_name_has_no_email() {
+    let input = "Proper Name <proper@example.com> Commit Name";
+    let err = try_line(input).unwrap_err();
+    assert!(matches!(
+        &err,
+        parse::Error::MissingCommitEmail { line_number: 1, name, .. } if name == "Commit Name"
+    ));
+    let spanned = gix_mailmap::parse_with_spans(input.as_bytes())
+        .next()
+        .expect("single line")
+        .unwrap_err();
+    assert_eq!(&input[spanned.span], "Commit Name");
+    assert_eq!(
+        err.to_string(),
+        "Line 1 has the commit name \"Commit Name\" without an email, but commits are always matched by email, optionally along with their name"
+    );
+
+    assert!(matches!(
+        try_line(r#"Proper Name <proper@example.com> "Commit Name" # comment"#),
+        Err(parse::Error::MissingCommitEmail { line_number: 1, name, .. }) if name == "Commit Name"
+    ));
+    assert!(matches!(
+        try_line("<proper@example.com> Commit Name"),
+        Err(parse::Error::MissingCommitEmail { line_number: 1, name, .. }) if name == "Commit Name"
     ));
 }
 
@@ -106,18 +243,72 @@ fn error_if_there_is_just_an_email() {
 fn error_if_email_is_empty() {
     assert!(matches!(
         try_line("hello <"),
-        Err(parse::Error::Malformed { line_number: 1, .. })
+        Err(parse::Error::MissingClosingBracket { line_number: 1, fragment, .. }) if fragment == "<"
     ));
     assert!(matches!(
         try_line("hello < \t"),
-        Err(parse::Error::Malformed { line_number: 1, .. })
+        Err(parse::Error::MissingClosingBracket { line_number: 1, fragment, .. }) if fragment == "<"
     ));
     assert!(matches!(
         try_line("hello < \t\r >"),
-        Err(parse::Error::Malformed { line_number: 1, .. })
+        Err(parse::Error::EmptyEmail { line_number: 1, fragment, .. }) if fragment == "< \t\r >"
+    ));
+    assert!(matches!(
+        try_line("Proper Name <>"),
+        Err(parse::Error::EmptyEmail { line_number: 1, fragment, .. }) if fragment == "<>"
     ));
 }
 
+#[test]
+fn spans_point_to_names_and_emails_in_the_input() -> Result<(), parse::SpannedError> {
+    let input = "# comment\n  proper name <proper email> \"commit name\" < commit-email >\r\nname <email>";
+    let mut lines = gix_mailmap::parse_with_spans(input.as_bytes());
+    let (entry, spans) = lines.next().expect("first line")?;
+    assert_eq!(
+        entry,
+        Entry::change_name_and_email_by_name_and_email("proper name", "proper email", "commit name", "commit-email")
+    );
+    let new_name = spans.new_name.expect("present");
+    assert_eq!(&input[new_name.clone()], "proper name");
+    assert_eq!(new_name.start, 12);
+    assert_eq!(&input[spans.new_email.expect("present")], "proper email");
+    assert_eq!(&input[spans.old_name.expect("present")], "commit name");
+    assert_eq!(
+        &input[spans.old_email], "commit-email",
+        "the span of the email is the trimmed content between the brackets"
+    );
+
+    let (entry, spans) = lines.next().expect("second line")?;
+    assert_eq!(entry, Entry::change_name_by_email("name", "email"));
+    assert_eq!(&input[spans.new_name.expect("present")], "name");
+    assert_eq!(spans.new_email, None);
+    assert_eq!(spans.old_name, None);
+    assert_eq!(&input[spans.old_email], "email");
+    assert!(lines.next().is_none());
+    Ok(())
+}
+
+#[test]
+fn spans_of_errors_point_to_the_offending_fragment() {
+    let input = "a <a@example.com>\n  b <b@example.com> <unclosed\n";
+    let err = gix_mailmap::parse_with_spans(input.as_bytes())
+        .nth(1)
+        .expect("second line")
+        .unwrap_err();
+    assert!(matches!(
+        err.error,
+        parse::Error::MissingClosingBracket { line_number: 2, .. }
+    ));
+    assert_eq!(&input[err.span], "<unclosed");
+
+    let err = gix_mailmap::parse_with_spans(b"  just a name ")
+        .next()
+        .expect("single line")
+        .unwrap_err();
+    assert!(matches!(err.error, parse::Error::MissingEmail { line_number: 1, .. }));
+    assert_eq!(err.span, 2..13);
+}
+
 fn line(input: &str) -> Entry<'_> {
     try_line(input).unwrap()
 }
@@ -52,6 +52,127 @@ fn try_resolve() {
     assert_eq!(snapshot.entries().len(), 5);
 }
 
+#[test]
+fn resolve_ref_like_git() {
+    let snapshot = Snapshot::from_bytes(&fixture_bytes("git-t4203.txt"));
+    let resolve = |name: &str, email: &str| {
+        let sig = signature(name, email);
+        let actual = snapshot.resolve_ref(sig.to_ref());
+        (actual.name.to_string(), actual.email.to_string(), actual.time)
+    };
+    let expected = |name: &str, email: &str| (name.to_string(), email.to_string(), signature(name, email).time);
+
+    assert_eq!(
+        resolve("author", "author@example.com"),
+        expected("A U Thor", "author@example.com"),
+        "name by email"
+    );
+    assert_eq!(
+        resolve("nick1", "bugs@company.xx"),
+        expected("Some Dude", "some@dude.xx"),
+        "name and email by name and email"
+    );
+    assert_eq!(
+        resolve("nick2", "bugs@company.xx"),
+        expected("Other Author", "other@author.xx"),
+        "the same email maps differently depending on the name"
+    );
+    assert_eq!(
+        resolve("nick3", "bugs@company.xx"),
+        expected("nick3", "bugs@company.xx"),
+        "an email with only name-specific mappings doesn't match unknown names"
+    );
+    assert_eq!(
+        resolve("nick2", "nick2@company.xx"),
+        expected("Other Author", "other@author.xx"),
+        "name and email by email"
+    );
+    assert_eq!(
+        resolve("CTO", "ME@Company.xx"),
+        expected("Santa Claus", "santa.claus@northpole.xx"),
+        "emails are matched case-insensitively"
+    );
+    assert_eq!(
+        resolve("Nick1", "BUGS@company.xx"),
+        expected("Some Dude", "some@dude.xx"),
+        "case-insensitive matching also applies to the name and email combination"
+    );
+    assert_eq!(
+        resolve("unknown", "unknown@example.com"),
+        expected("unknown", "unknown@example.com"),
+        "unmatched signatures are returned as is"
+    );
+}
+
+#[test]
+fn emails_are_matched_case_insensitively() {
+    let snapshot = Snapshot::from_bytes(b"Name <Foo@Example.COM>");
+    assert_eq!(
+        snapshot.try_resolve(signature("author", "foo@example.com").to_ref()),
+        Some(signature("Name", "Foo@Example.COM")),
+        "the email is normalized to the one in the mailmap"
+    );
+    assert_eq!(
+        snapshot.try_resolve(signature("author", "FOO@EXAMPLE.COM").to_ref()),
+        Some(signature("Name", "Foo@Example.COM"))
+    );
+}
+
+#[test]
+fn old_names_are_matched_case_insensitively_but_new_names_keep_their_case() {
+    let snapshot =
+        Snapshot::from_bytes(b"New Name <new@example.com> Old Name <old@example.com>\nOther <other@example.com>");
+    assert_eq!(
+        snapshot.try_resolve(signature("old name", "old@example.com").to_ref()),
+        Some(signature("New Name", "new@example.com")),
+        "like in git, the old name is matched without regard to ASCII case"
+    );
+    assert_eq!(
+        snapshot.try_resolve(signature("OLD NAME", "old@example.com").to_ref()),
+        Some(signature("New Name", "new@example.com"))
+    );
+    assert_eq!(
+        snapshot.try_resolve(signature("Old  Name", "old@example.com").to_ref()),
+        None,
+        "everything but the case has to match exactly"
+    );
+    assert_eq!(
+        snapshot.try_resolve(signature("author", "OTHER@example.com").to_ref()),
+        Some(signature("Other", "other@example.com")),
+        "the new name is used as written in the mailmap"
+    );
+    let sig = signature("mIxEd CaSe", "unknown@example.com");
+    assert_eq!(snapshot.resolve(sig.to_ref()), sig, "unmapped names keep their case");
+}
+
+#[test]
+fn owned_entries_map_identically_to_borrowed_ones() {
+    let buf = fixture_bytes("typical.txt");
+    let borrowed = Snapshot::from_bytes(&buf);
+    let owned: Vec<gix_mailmap::OwnedEntry> = gix_mailmap::parse_ignore_errors(&buf)
+        .map(|entry| entry.to_owned())
+        .collect();
+    drop(buf);
+
+    let from_owned = Snapshot::new(owned.iter().map(gix_mailmap::OwnedEntry::to_ref));
+    assert_eq!(from_owned.entries(), borrowed.entries());
+    for sig in [
+        signature("Foo", "Joe@example.com"),
+        signature("Joe", "bugs@example.com"),
+        signature("Jane", "jane@laptop.(none)"),
+        signature("Jean", "bugs@example.com"),
+    ] {
+        assert_eq!(from_owned.try_resolve(sig.to_ref()), borrowed.try_resolve(sig.to_ref()));
+    }
+
+    let entry = gix_mailmap::Entry::change_name_and_email_by_name_and_email("a", "b", "c", "d");
+    assert_eq!(
+        gix_mailmap::OwnedEntry::from(entry.clone()).to_ref(),
+        entry,
+        "conversions are lossless"
+    );
+}
+
 #[test]
 fn non_name_and_name_mappings_will_not_clash() {
     let entries = vec![
@@ -27,19 +27,32 @@ pub fn message<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(i: &'a [u8]
 
 pub fn commit<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
     i: &'a [u8],
+) -> IResult<&'a [u8], CommitRef<'_>, E> {
+    commit_with_signature(i, parse::signature)
+}
+
+/// Like [`commit()`], but tolerates signatures without timezone.
+pub fn commit_lenient<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+    i: &'a [u8],
+) -> IResult<&'a [u8], CommitRef<'_>, E> {
+    commit_with_signature(i, parse::signature_lenient)
+}
+
+fn commit_with_signature<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+    i: &'a [u8],
+    signature: impl Fn(&'a [u8]) -> IResult<&'a [u8], gix_actor::SignatureRef<'a>, E> + Copy,
 ) -> IResult<&'a [u8], CommitRef<'_>, E> {
     let (i, tree) = context("tree <40 lowercase hex char>", |i| {
         parse::header_field(i, b"tree", parse::hex_hash)
     })(i)?;
+    let hash_kind = parse::hash_kind_of(tree);
     let (i, parents) = context(
         "zero or more 'parent <40 lowercase hex char>'",
-        many0(|i| parse::header_field(i, b"parent", parse::hex_hash)),
+        many0(|i| parse::header_field(i, b"parent", parse::hex_hash_exact(hash_kind))),
     )(i)?;
-    let (i, author) = context("author <signature>", |i| {
-        parse::header_field(i, b"author", parse::signature)
-    })(i)?;
+    let (i, author) = context("author <signature>", |i| parse::header_field(i, b"author", signature))(i)?;
     let (i, committer) = context("committer <signature>", |i| {
-        parse::header_field(i, b"committer", parse::signature)
+        parse::header_field(i, b"committer", signature)
     })(i)?;
     let (i, encoding) = context(
         "encoding <encoding>",
@@ -28,7 +28,9 @@ pub struct TrailerRef<'a> {
     #[cfg_attr(feature = "serde1", serde(borrow))]
     pub token: &'a BStr,
     /// The value right after the separator ": ", with leading and trailing whitespace trimmed.
-    /// Note that multi-line values aren't currently supported.
+    ///
+    /// Values continued on the following lines, which start with whitespace, are returned as they are, including the newlines
+    /// and whitespace in between.
     pub value: &'a BStr,
 }
 
@@ -41,6 +43,34 @@ fn parse_single_line_trailer<'a, E: ParseError<&'a [u8]>>(i: &'a [u8]) -> IResul
     }
 }
 
+/// Prefixes of trailer lines that git adds by itself, which make paragraphs with fewer trailers count as trailer block.
+const GIT_GENERATED_PREFIXES: &[&[u8]] = &[b"Signed-off-by: ", b"(cherry picked from commit "];
+
+/// Return `true` if `paragraph` is a trailer block. Just like in git, this is the case if all of its lines are trailers
+/// or their continuations, or if at least 25% of its lines are trailers and one of them was generated by git.
+fn is_trailer_block(paragraph: &[u8]) -> bool {
+    let (mut num_trailers, mut num_non_trailers) = (0, 0);
+    let mut saw_git_generated = false;
+    let mut in_trailer = false;
+    for line in paragraph.lines() {
+        if line.trim().is_empty() || (in_trailer && (line.starts_with(b" ") || line.starts_with(b"\t"))) {
+            continue;
+        }
+        if GIT_GENERATED_PREFIXES.iter().any(|prefix| line.starts_with(prefix)) {
+            saw_git_generated = true;
+            in_trailer = true;
+        } else {
+            in_trailer = all_consuming(parse_single_line_trailer::<()>)(line).is_ok();
+        }
+        if in_trailer {
+            num_trailers += 1;
+        } else {
+            num_non_trailers += 1;
+        }
+    }
+    num_trailers > 0 && (num_non_trailers == 0 || (saw_git_generated && num_trailers * 3 >= num_non_trailers))
+}
+
 impl<'a> Iterator for Trailers<'a> {
     type Item = TrailerRef<'a>;
 
@@ -49,16 +79,26 @@ impl<'a> Iterator for Trailers<'a> {
             return None;
         }
         for line in self.cursor.lines_with_terminator() {
+            let input = self.cursor;
             self.cursor = &self.cursor[line.len()..];
-            if let Some(trailer) =
-                all_consuming(parse_single_line_trailer::<()>)(line)
-                    .ok()
-                    .map(|(_, (token, value))| TrailerRef {
-                        token: token.trim().as_bstr(),
-                        value: value.trim().as_bstr(),
-                    })
-            {
-                return Some(trailer);
+            if let Ok((_, (token, value))) = all_consuming(parse_single_line_trailer::<()>)(line) {
+                let value_start = token.len() + 2;
+                let mut value_end = value_start + value.len();
+                let mut consumed = line.len();
+                for continuation in self
+                    .cursor
+                    .lines_with_terminator()
+                    .take_while(|line| line.starts_with(b" ") || line.starts_with(b"\t"))
+                    .take_while(|line| !line.trim().is_empty())
+                {
+                    value_end = consumed + continuation.trim_end().len();
+                    consumed += continuation.len();
+                }
+                self.cursor = &input[consumed..];
+                return Some(TrailerRef {
+                    token: token.trim().as_bstr(),
+                    value: input[value_start..value_end].as_bstr(),
+                });
             }
         }
         None
@@ -67,6 +107,10 @@ impl<'a> Iterator for Trailers<'a> {
 
 impl<'a> BodyRef<'a> {
     /// Parse `body` bytes into the trailer and the actual body.
+    ///
+    /// The last paragraph is only considered a trailer if it's a trailer block according to the rules of
+    /// `git interpret-trailers`, so it must consist of trailers only, or contain at least 25% of trailers
+    /// with one of them generated by git, like `Signed-off-by`.
     pub fn from_bytes(body: &'a [u8]) -> Self {
         body.rfind(b"\n\n")
             .map(|pos| (2, pos))
@@ -74,7 +118,7 @@ impl<'a> BodyRef<'a> {
             .and_then(|(sep_len, pos)| {
                 let trailer = &body[pos + sep_len..];
                 let body = &body[..pos];
-                Trailers { cursor: trailer }.next().map(|_| BodyRef {
+                is_trailer_block(trailer).then(|| BodyRef {
                     body_without_trailer: body.as_bstr(),
                     start_of_trailer: trailer,
                 })
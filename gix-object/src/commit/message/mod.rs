@@ -27,7 +27,12 @@ impl<'a> MessageRef<'a> {
     /// Parse the given `input` as message.
     ///
     /// Note that this cannot fail as everything will be interpreted as title if there is no body separator.
-    pub fn from_bytes(input: &'a [u8]) -> Self {
+    /// Leading empty lines are skipped, while leading whitespace of the title is retained. Note that previously, leading
+    /// empty lines ended up as empty `title`, with everything after them being the `body`.
+    pub fn from_bytes(mut input: &'a [u8]) -> Self {
+        while let Some(rest) = input.strip_prefix(b"\n").or_else(|| input.strip_prefix(b"\r\n")) {
+            input = rest;
+        }
         let (title, body) = decode::message(input);
         MessageRef { title, body }
     }
@@ -51,7 +56,13 @@ impl<'a> MessageRef<'a> {
     }
 }
 
-pub(crate) fn summary(message: &BStr) -> Cow<'_, BStr> {
+/// Produce a short summary of the title of the entire commit `message`, like [`MessageRef::summary()`] after
+/// parsing `message` with [`MessageRef::from_bytes()`].
+///
+/// If `message` doesn't contain two consecutive newlines, it is entirely used as title.
+/// Unlike [`MessageRef::from_bytes()`], which only skips leading empty lines, all leading whitespace is skipped here,
+/// so leading lines that consist of whitespace only don't end up as title.
+pub fn summary(message: &BStr) -> Cow<'_, BStr> {
     let message = message.trim();
     match message.find_byte(b'\n') {
         Some(mut pos) => {
@@ -87,6 +98,14 @@ pub(crate) fn summary(message: &BStr) -> Cow<'_, BStr> {
     }
 }
 
+/// Return the body of the entire commit `message`, which is everything after the title and the two consecutive newlines
+/// separating it, or `None` if there is no body.
+///
+/// This is the same as the `body` field of [`MessageRef`] after parsing `message` with [`MessageRef::from_bytes()`].
+pub fn body(message: &BStr) -> Option<&BStr> {
+    MessageRef::from_bytes(message).body
+}
+
 /// A reference to a message body, further parsed to only contain the non-trailer parts.
 ///
 /// See [git-interpret-trailers](https://git-scm.com/docs/git-interpret-trailers) for more information
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use bstr::{BStr, ByteSlice};
 
 use crate::{Commit, CommitRef, TagRef};
@@ -24,6 +26,12 @@ pub struct MessageRef<'a> {
 ///
 pub mod ref_iter;
 
+/// Return an iterator over the trailers of the entire commit `message`, which are parsed from its last paragraph
+/// if it's a trailer block, just like [`CommitRef::message_trailers()`] does.
+pub fn trailers(message: &BStr) -> message::body::Trailers<'_> {
+    message::BodyRef::from_bytes(message).trailers()
+}
+
 mod write;
 
 impl<'a> CommitRef<'a> {
@@ -31,6 +39,14 @@ impl<'a> CommitRef<'a> {
     pub fn from_bytes(data: &'a [u8]) -> Result<CommitRef<'a>, crate::decode::Error> {
         decode::commit(data).map(|(_, t)| t).map_err(crate::decode::Error::from)
     }
+
+    /// Like [`from_bytes()`][CommitRef::from_bytes()], but tolerates author and committer signatures without timezone,
+    /// as found in some historical repositories. These are assumed to be in UTC, i.e. `+0000`.
+    pub fn from_bytes_lenient(data: &'a [u8]) -> Result<CommitRef<'a>, crate::decode::Error> {
+        decode::commit_lenient(data)
+            .map(|(_, t)| t)
+            .map_err(crate::decode::Error::from)
+    }
     /// Return the `tree` fields hash digest.
     pub fn tree(&self) -> gix_hash::ObjectId {
         gix_hash::ObjectId::from_hex(self.tree).expect("prior validation of tree hash during parsing")
@@ -80,6 +96,27 @@ impl Commit {
     }
 }
 
+/// Return an iterator over all headers of the commit in `data` as `(name, value)` pairs in order of appearance,
+/// including `tree`, `parent`, `author` and `committer`.
+///
+/// Multi-line values have their continuation lines unfolded, which is the only case in which values are allocated.
+/// The iteration stops at the empty line separating the headers from the message, or at the first malformed header.
+pub fn headers(data: &[u8]) -> impl Iterator<Item = (&BStr, Cow<'_, BStr>)> {
+    let mut data = data;
+    std::iter::from_fn(move || {
+        let (rest, header) = nom::branch::alt((
+            |i| crate::parse::any_header_field_multi_line::<()>(i).map(|(i, (k, o))| (i, (k.as_bstr(), Cow::Owned(o)))),
+            |i| {
+                crate::parse::any_header_field::<_, ()>(i, nom::bytes::complete::is_not(crate::parse::NL))
+                    .map(|(i, (k, o))| (i, (k.as_bstr(), Cow::Borrowed(o.as_bstr()))))
+            },
+        ))(data)
+        .ok()?;
+        data = rest;
+        Some(header)
+    })
+}
+
 /// An iterator over extra headers in [owned][crate::Commit] and [borrowed][crate::CommitRef] commits.
 pub struct ExtraHeaders<I> {
     inner: I,
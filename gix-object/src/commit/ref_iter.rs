@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use bstr::BStr;
+use bstr::{BStr, BString};
 use gix_hash::{oid, ObjectId};
 use nom::{
     branch::alt,
@@ -20,7 +20,7 @@ pub(crate) enum SignatureKind {
 #[derive(Copy, Clone)]
 pub(crate) enum State {
     Tree,
-    Parents,
+    Parents { hash_kind: gix_hash::Kind },
     Signature { of: SignatureKind },
     Encoding,
     ExtraHeaders,
@@ -42,6 +42,29 @@ impl<'a> CommitRefIter<'a> {
         }
     }
 
+    /// Parse `data` as commit and return its PGP or SSH signature as stored in the `gpgsig` or `gpgsig-sha256` header
+    /// along with the signed data, which is `data` without the signature header.
+    ///
+    /// The signed data is what needs to be passed to `gpg` or `ssh-keygen` to verify the signature.
+    /// Returns `None` if the commit isn't signed.
+    pub fn signature(data: &'a [u8]) -> Result<Option<(Cow<'a, BStr>, BString)>, crate::decode::Error> {
+        let mut iter = CommitRefIter::from_bytes(data);
+        loop {
+            let header_start = data.len() - iter.data.len();
+            match iter.next() {
+                None => return Ok(None),
+                Some(Err(err)) => return Err(err),
+                Some(Ok(Token::ExtraHeader((name, signature)))) if name == "gpgsig" || name == "gpgsig-sha256" => {
+                    let header_end = data.len() - iter.data.len();
+                    let mut signed_data = BString::from(&data[..header_start]);
+                    signed_data.extend_from_slice(&data[header_end..]);
+                    return Ok(Some((signature, signed_data)));
+                }
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+
     /// Returns the object id of this commits tree if it is the first function called and if there is no error in decoding
     /// the data.
     ///
@@ -125,7 +148,9 @@ impl<'a> CommitRefIter<'a> {
                 let (i, tree) = context("tree <40 lowercase hex char>", |i| {
                     parse::header_field(i, b"tree", parse::hex_hash)
                 })(i)?;
-                *state = State::Parents;
+                *state = State::Parents {
+                    hash_kind: parse::hash_kind_of(tree),
+                };
                 (
                     i,
                     Token::Tree {
@@ -133,10 +158,11 @@ impl<'a> CommitRefIter<'a> {
                     },
                 )
             }
-            Parents => {
+            Parents { hash_kind } => {
+                let hash_kind = *hash_kind;
                 let (i, parent) = context(
                     "commit <40 lowercase hex char>",
-                    opt(|i| parse::header_field(i, b"parent", parse::hex_hash)),
+                    opt(|i| parse::header_field(i, b"parent", parse::hex_hash_exact(hash_kind))),
                 )(i)?;
                 match parent {
                     Some(parent) => (
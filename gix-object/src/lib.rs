@@ -32,7 +32,8 @@ mod traits;
 pub use traits::WriteTo;
 
 pub mod encode;
-pub(crate) mod parse;
+///
+pub mod parse;
 
 ///
 pub mod kind;
@@ -1,6 +1,7 @@
 use bstr::{BStr, BString, ByteVec};
 use nom::{
-    bytes::complete::{is_not, tag, take_until, take_while_m_n},
+    bytes::complete::{is_not, tag, take, take_until, take_while1, take_while_m_n},
+    character::is_digit,
     combinator::{peek, recognize},
     error::{context, ContextError, ParseError},
     multi::many1_count,
@@ -65,6 +66,8 @@ fn is_hex_digit_lc(b: u8) -> bool {
     matches!(b, b'0'..=b'9' | b'a'..=b'f')
 }
 
+/// Parse between the shortest and the longest amount of lowercase hex characters of all supported hash kinds,
+/// consuming as many of them as possible.
 pub fn hex_hash<'a, E: ParseError<&'a [u8]>>(i: &'a [u8]) -> IResult<&'a [u8], &'a BStr, E> {
     take_while_m_n(
         gix_hash::Kind::shortest().len_in_hex(),
@@ -74,8 +77,94 @@ pub fn hex_hash<'a, E: ParseError<&'a [u8]>>(i: &'a [u8]) -> IResult<&'a [u8], &
     .map(|(i, hex)| (i, hex.as_bstr()))
 }
 
+/// Parse exactly as many lowercase hex characters as are needed to represent a hash of `kind`, without consuming more.
+///
+/// Use it over [`hex_hash()`] if the hash kind is known, as the latter accepts hashes of all lengths we support.
+pub fn hex_hash_exact<'a, E: ParseError<&'a [u8]>>(
+    kind: gix_hash::Kind,
+) -> impl Fn(&'a [u8]) -> IResult<&'a [u8], &'a BStr, E> {
+    move |i| take_while_m_n(kind.len_in_hex(), kind.len_in_hex(), is_hex_digit_lc)(i).map(|(i, hex)| (i, hex.as_bstr()))
+}
+
+/// Return the kind of hash whose hex representation is `hex`, as previously parsed with [`hex_hash()`].
+pub(crate) fn hash_kind_of(hex: &BStr) -> gix_hash::Kind {
+    gix_hash::Kind::from_hex_len(hex.len()).expect("hex_hash() only yields hashes of known kinds")
+}
+
 pub(crate) fn signature<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
     i: &'a [u8],
 ) -> IResult<&'a [u8], gix_actor::SignatureRef<'a>, E> {
     gix_actor::signature::decode(i)
 }
+
+/// Like [`signature()`], but if the timezone is missing, a zero offset is assumed, i.e. `+0000`.
+pub(crate) fn signature_lenient<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+    i: &'a [u8],
+) -> IResult<&'a [u8], gix_actor::SignatureRef<'a>, E> {
+    signature(i).or_else(|err| match err {
+        nom::Err::Error(_) => signature_without_timezone(i),
+        err => Err(err),
+    })
+}
+
+fn signature_without_timezone<'a, E: ParseError<&'a [u8]> + ContextError<&'a [u8]>>(
+    i: &'a [u8],
+) -> IResult<&'a [u8], gix_actor::SignatureRef<'a>, E> {
+    let (i, (name, email, time)) = context(
+        "<name> <<email>> <timestamp>",
+        tuple((
+            context("<name>", terminated(take_until(&b" <"[..]), take(2usize))),
+            context("<email>", terminated(take_until(&b"> "[..]), take(2usize))),
+            context("<timestamp>", |i| {
+                take_while1(is_digit)(i).and_then(|(i, v)| {
+                    btoi::btoi::<u32>(v)
+                        .map(|v| (i, v))
+                        .map_err(|_| nom::Err::Error(E::from_error_kind(i, nom::error::ErrorKind::MapRes)))
+                })
+            }),
+        )),
+    )(i)?;
+    Ok((
+        i,
+        gix_actor::SignatureRef {
+            name: name.as_bstr(),
+            email: email.as_bstr(),
+            time: gix_actor::Time {
+                seconds_since_unix_epoch: time,
+                offset_in_seconds: 0,
+                sign: gix_actor::Sign::Plus,
+            },
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Result<'a, T> = IResult<&'a [u8], T, ()>;
+
+    #[test]
+    fn hex_hash_exact_stops_at_the_hash_length() {
+        let sha1 = "0123456789abcdef0123456789abcdef01234567";
+        let input = format!("{sha1}89abcdef");
+        let res: Result<'_, _> = hex_hash_exact(gix_hash::Kind::Sha1)(input.as_bytes());
+        assert_eq!(res, Ok((&b"89abcdef"[..], sha1.as_bytes().as_bstr())));
+    }
+
+    #[test]
+    fn hex_hash_consumes_up_to_the_longest_hash_length() {
+        let sha1 = "0123456789abcdef0123456789abcdef01234567";
+        let input = format!("{sha1}89abcdef");
+        let res: Result<'_, _> = hex_hash(input.as_bytes());
+        assert_eq!(res, Ok((&b"89abcdef"[..], sha1.as_bytes().as_bstr())));
+        let res: Result<'_, _> = hex_hash(b"0123456789abcdef 123");
+        assert!(res.is_err(), "abbreviated hashes are rejected as well");
+    }
+
+    #[test]
+    fn hex_hash_exact_requires_the_full_hash_length() {
+        let res: Result<'_, _> = hex_hash_exact(gix_hash::Kind::Sha1)(b"0123456789abcdef 123");
+        assert!(res.is_err(), "abbreviated hashes are rejected");
+    }
+}
@@ -9,6 +9,17 @@ mod ref_iter;
 ///
 pub mod write;
 
+///
+pub mod mode {
+    /// The Error used in [`EntryMode::from_bytes()`][super::EntryMode::from_bytes()].
+    #[derive(Debug, Clone, thiserror::Error)]
+    #[allow(missing_docs)]
+    pub enum Error {
+        #[error("Invalid tree entry mode: {mode:?}")]
+        InvalidMode { mode: bstr::BString },
+    }
+}
+
 /// The mode of items storable in a tree, similar to the file mode on a unix file system.
 ///
 /// Used in [mutable::Entry][crate::tree::Entry] and [EntryRef].
@@ -44,6 +55,21 @@ impl EntryMode {
         matches!(self, EntryMode::Blob | EntryMode::BlobExecutable)
     }
 
+    /// Return true if the entry is a blob with its executable bit set.
+    pub fn is_executable(&self) -> bool {
+        *self == EntryMode::BlobExecutable
+    }
+
+    /// Return true if the entry is a symbolic link.
+    pub fn is_link(&self) -> bool {
+        *self == EntryMode::Link
+    }
+
+    /// Return true if the entry is the commit of a submodule.
+    pub fn is_commit(&self) -> bool {
+        *self == EntryMode::Commit
+    }
+
     /// Return true if the entry is any kind of blob or symlink.
     pub fn is_blob_or_symlink(&self) -> bool {
         matches!(self, EntryMode::Blob | EntryMode::BlobExecutable | EntryMode::Link)
@@ -122,6 +148,14 @@ impl Ord for Entry {
 
 /// Serialization
 impl EntryMode {
+    /// Parse the octal `mode` as found in the git internal format, like `b"100644"`.
+    ///
+    /// Only modes git itself would write are accepted, along with a few non-canonical blob modes that are known
+    /// to exist in the wild and are treated as [`Blob`][EntryMode::Blob]. Anything else, like `b"100777"`, is an error.
+    pub fn from_bytes(mode: &[u8]) -> Result<EntryMode, mode::Error> {
+        EntryMode::try_from(mode).map_err(|mode| mode::Error::InvalidMode { mode: mode.into() })
+    }
+
     /// Return the representation as used in the git internal format.
     pub fn as_bytes(&self) -> &'static [u8] {
         use EntryMode::*;
@@ -123,17 +123,17 @@ mod decode {
         let mode = tree::EntryMode::try_from(mode).ok()?;
         let (filename, i) = i.split_at(i.find_byte(0)?);
         let i = &i[1..];
-        const HASH_LEN_FIXME: usize = 20; // TODO: know actual /desired length or we may overshoot
+        const HASH_LEN_FIXME: usize = gix_hash::Kind::Sha1.len_in_bytes(); // TODO: know actual /desired length or we may overshoot
         let (oid, i) = match i.len() {
             len if len < HASH_LEN_FIXME => return None,
-            _ => i.split_at(20),
+            _ => i.split_at(HASH_LEN_FIXME),
         };
         Some((
             i,
             EntryRef {
                 mode,
                 filename: filename.as_bstr(),
-                oid: gix_hash::oid::try_from_bytes(oid).expect("we counted exactly 20 bytes"),
+                oid: gix_hash::oid::try_from_bytes(oid).expect("we counted exactly the amount of bytes of a hash"),
             },
         ))
     }
@@ -143,14 +143,14 @@ mod decode {
         let mode = tree::EntryMode::try_from(mode)
             .map_err(|invalid| nom::Err::Error(E::from_error_kind(invalid, nom::error::ErrorKind::MapRes)))?;
         let (i, filename) = terminated(take_while1(|b| b != NULL[0]), tag(NULL))(i)?;
-        let (i, oid) = take(20u8)(i)?; // TODO: make this compatible with other hash lengths
+        let (i, oid) = take(gix_hash::Kind::Sha1.len_in_bytes())(i)?; // TODO: make this compatible with other hash lengths
 
         Ok((
             i,
             EntryRef {
                 mode,
                 filename: filename.as_bstr(),
-                oid: gix_hash::oid::try_from_bytes(oid).expect("we counted exactly 20 bytes"),
+                oid: gix_hash::oid::try_from_bytes(oid).expect("we counted exactly the amount of bytes of a hash"),
             },
         ))
     }
@@ -2,9 +2,12 @@ use gix_actor::{Sign, SignatureRef, Time};
 use gix_object::{bstr::ByteSlice, commit::message::body::TrailerRef, CommitRef};
 use smallvec::SmallVec;
 
-use crate::immutable::{
-    commit::{LONG_MESSAGE, MERGE_TAG, SIGNATURE},
-    fixture_bytes, linus_signature, signature,
+use crate::{
+    hex_to_id,
+    immutable::{
+        commit::{LONG_MESSAGE, MERGE_TAG, SIGNATURE},
+        fixture_bytes, linus_signature, signature,
+    },
 };
 
 #[test]
@@ -24,6 +27,51 @@ fn unsigned() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn lenient_parsing_tolerates_missing_timezone() -> crate::Result {
+    let input = fixture_bytes("commit", "no-timezone.txt");
+    assert!(
+        CommitRef::from_bytes(&input).is_err(),
+        "strict parsing requires a timezone"
+    );
+    assert_eq!(
+        CommitRef::from_bytes_lenient(&input)?,
+        CommitRef {
+            tree: b"1b2dfb4ac5e42080b682fc676e9738c94ce6d54d".as_bstr(),
+            parents: SmallVec::default(),
+            author: SignatureRef {
+                time: Time {
+                    seconds_since_unix_epoch: 1592437401,
+                    offset_in_seconds: 0,
+                    sign: Sign::Plus,
+                },
+                ..signature(1592437401)
+            },
+            committer: signature(1592437401),
+            encoding: None,
+            message: b"without timezone in author line\n".as_bstr(),
+            extra_headers: vec![]
+        }
+    );
+    let input = fixture_bytes("commit", "unsigned.txt");
+    assert_eq!(
+        CommitRef::from_bytes_lenient(&input)?,
+        CommitRef::from_bytes(&input)?,
+        "well-formed commits parse the same"
+    );
+    Ok(())
+}
+
+#[test]
+fn negative_timestamps_are_unsupported() {
+    let input = fixture_bytes("commit", "negative-timestamp.txt");
+    assert!(CommitRef::from_bytes(&input).is_err());
+    assert!(
+        CommitRef::from_bytes_lenient(&input).is_err(),
+        "lenient parsing doesn't change this"
+    );
+}
+
 #[test]
 fn whitespace() -> crate::Result {
     assert_eq!(
@@ -133,6 +181,20 @@ fn with_encoding() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn with_encoding_and_non_utf8_message() -> crate::Result {
+    let mut input = fixture_bytes("commit", "with-encoding.txt");
+    input.extend_from_slice(b"\n\nd\xe9j\xe0 vu, in Latin-1");
+    let commit = CommitRef::from_bytes(&input)?;
+    assert_eq!(commit.encoding, Some("ISO-8859-1".into()));
+    assert_eq!(
+        commit.message,
+        b"commit with encoding\n\nd\xe9j\xe0 vu, in Latin-1".as_bstr(),
+        "the message is returned unmodified, it's up to the caller to transcode it"
+    );
+    Ok(())
+}
+
 #[test]
 fn with_trailer() -> crate::Result {
     let kim = SignatureRef {
@@ -223,8 +285,18 @@ instead of depending directly on the lower-level crates.
 
 #[test]
 fn merge() -> crate::Result {
+    let input = fixture_bytes("commit", "merge.txt");
+    let commit = CommitRef::from_bytes(&input)?;
     assert_eq!(
-        CommitRef::from_bytes(&fixture_bytes("commit", "merge.txt"))?,
+        commit.parents().collect::<Vec<_>>(),
+        vec![
+            hex_to_id("6a6054db4ce3c1e4e6a37f8c4d7acb63a4d6ad71"),
+            hex_to_id("c91d592913d47ac4e4a76daf16fd649b276e211e")
+        ],
+        "parents are returned in the order of their headers, with the first parent first"
+    );
+    assert_eq!(
+        commit,
         CommitRef {
             tree: b"0cf16ce8e229b59a761198975f0c0263229faf82".as_bstr(),
             parents: SmallVec::from(vec![
@@ -175,11 +175,11 @@ fn mergetag() -> crate::Result {
 }
 
 mod method {
-    use gix_object::CommitRefIter;
+    use gix_object::{bstr::ByteSlice, CommitRefIter};
 
     use crate::{
         hex_to_id,
-        immutable::{fixture_bytes, signature},
+        immutable::{commit::SIGNATURE, fixture_bytes, signature},
     };
 
     #[test]
@@ -198,6 +198,39 @@ mod method {
         Ok(())
     }
 
+    #[test]
+    fn signature_and_signed_data() -> crate::Result {
+        let expected_signed_data = b"tree 00fc39317701176e326974ce44f5bd545a32ec0b
+parent 09d8d3a12e161a7f6afb522dbe8900a9c09bce06
+author Sebastian Thiel <sebastian.thiel@icloud.com> 1592391367 +0800
+committer Sebastian Thiel <sebastian.thiel@icloud.com> 1592391367 +0800
+
+update tasks
+";
+        for fixture in ["signed.txt", "signed-sha256-header.txt"] {
+            let input = fixture_bytes("commit", fixture);
+            let (signature, signed_data) = CommitRefIter::signature(&input)?.expect("signed commit");
+            assert!(signature.starts_with(b"-----BEGIN PGP SIGNATURE-----\n\niQEzBAABCAAdFiEEdjYp"));
+            assert!(signature.ends_with(b"=5ITV\n-----END PGP SIGNATURE-----"));
+            assert_eq!(signed_data, expected_signed_data.as_bstr(), "{fixture}");
+        }
+
+        let input = fixture_bytes("commit", "signed-with-encoding.txt");
+        let (signature, signed_data) = CommitRefIter::signature(&input)?.expect("signed commit");
+        assert_eq!(signature.as_ref(), SIGNATURE.as_bstr());
+        assert!(
+            signed_data.ends_with(b"encoding ISO-8859-1\n\nencoding & sig"),
+            "headers before the signature are retained"
+        );
+
+        assert_eq!(
+            CommitRefIter::signature(&fixture_bytes("commit", "unsigned.txt"))?,
+            None,
+            "unsigned commits have no signature"
+        );
+        Ok(())
+    }
+
     #[test]
     fn signatures() -> crate::Result {
         let input = fixture_bytes("commit", "unsigned.txt");
@@ -195,7 +195,7 @@ mod body {
 
     #[test]
     fn two_trailers_with_broken_one_inbetween_after_a_few_paragraphs() {
-        let input = "foo\nbar\n\nbar\n\nbaz\n\na: b\ncannot parse this\r\nc: d\n";
+        let input = "foo\nbar\n\nbar\n\nbaz\n\na: b\ncannot parse this\r\nSigned-off-by: d\n";
         let body = body(input);
         assert_eq!(body.as_ref(), "foo\nbar\n\nbar\n\nbaz");
         assert_eq!(
@@ -206,13 +206,102 @@ mod body {
                     value: "b".into()
                 },
                 TrailerRef {
-                    token: "c".into(),
+                    token: "Signed-off-by".into(),
                     value: "d".into()
                 }
-            ]
+            ],
+            "a git-generated trailer makes it a trailer block despite lines that aren't trailers"
         )
     }
 
+    #[test]
+    fn a_broken_trailer_without_git_generated_trailer_is_no_trailer_block() {
+        let input = "foo\n\na: b\ncannot parse this\r\nc: d\n";
+        let body = body(input);
+        assert_eq!(body.as_ref(), input);
+        assert_eq!(body.trailers().count(), 0);
+    }
+
+    #[test]
+    fn prose_with_a_single_trailer_in_the_last_paragraph_is_no_trailer_block() {
+        let input = "body\n\nThis explains the change\nand also mentions\nFixes: #42\n";
+        let body = body(input);
+        assert_eq!(body.as_ref(), input);
+        assert_eq!(body.trailers().count(), 0);
+    }
+
+    #[test]
+    fn trailers_of_entire_messages() {
+        let message = "subject\n\nbody\n\nFixes: #42\nSigned-off-by: a\n".into();
+        assert_eq!(
+            gix_object::commit::trailers(message).collect::<Vec<_>>(),
+            vec![
+                TrailerRef {
+                    token: "Fixes".into(),
+                    value: "#42".into()
+                },
+                TrailerRef {
+                    token: "Signed-off-by".into(),
+                    value: "a".into()
+                }
+            ]
+        );
+        assert_eq!(
+            gix_object::commit::trailers("Fixes: the title\n".into()).count(),
+            0,
+            "the title is never a trailer"
+        );
+        assert_eq!(
+            gix_object::commit::trailers("subject\n\nSee a: b, it's\nsomething else\n".into()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn single_sign_off() {
+        let message = MessageRef::from_bytes(b"subject\n\nbody\n\nSigned-off-by: Jane Doe <jane@example.com>\n");
+        assert_eq!(
+            message.body().expect("present").trailers().collect::<Vec<_>>(),
+            vec![TrailerRef {
+                token: "Signed-off-by".into(),
+                value: "Jane Doe <jane@example.com>".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn multiple_trailers_with_multi_line_values() {
+        let input = "body\n\nFixes: #42\nCo-authored-by: a\n  continued\n\tand more \nSigned-off-by: b\n";
+        let body = body(input);
+        assert_eq!(body.as_ref(), "body");
+        assert_eq!(
+            body.trailers().collect::<Vec<_>>(),
+            vec![
+                TrailerRef {
+                    token: "Fixes".into(),
+                    value: "#42".into()
+                },
+                TrailerRef {
+                    token: "Co-authored-by".into(),
+                    value: "a\n  continued\n\tand more".into()
+                },
+                TrailerRef {
+                    token: "Signed-off-by".into(),
+                    value: "b".into()
+                }
+            ],
+            "continuation lines start with whitespace and are part of the value"
+        );
+    }
+
+    #[test]
+    fn colons_in_the_last_paragraph_do_not_make_trailers() {
+        let input = "body\n\nSee https://example.com/a:b for details,\nit happened at 10:30.";
+        let body = body(input);
+        assert_eq!(body.as_ref(), input);
+        assert_eq!(body.trailers().count(), 0);
+    }
+
     #[test]
     fn no_trailer_after_a_paragraph_windows() {
         let input = "foo\nbar\n\nbar\r\n\r\nbaz";
@@ -255,6 +344,7 @@ mod summary {
             summary,
             "both versions create the same result"
         );
+        assert_eq!(gix_object::commit::message::summary(input.as_bstr()), summary);
         summary
     }
 
@@ -293,4 +383,74 @@ mod summary {
         let input = b" \t\r\n \nhello\t \r\nworld \t\r \nfoo\n\nsomething else we ignore";
         assert_eq!(summary(input), Cow::Borrowed(b"hello world foo".as_bstr()));
     }
+
+    #[test]
+    fn wrapped_subject_lines_are_joined_and_trailing_whitespace_is_trimmed() {
+        let input = b"\n\nfirst line  \nsecond line\t\n\nbody\n";
+        assert_eq!(summary(input), Cow::Borrowed(b"first line second line".as_bstr()));
+        assert_eq!(
+            gix_object::commit::message::body(input.as_bstr()),
+            Some(b"body\n".as_bstr())
+        );
+    }
+}
+
+#[test]
+fn leading_empty_lines_are_skipped() {
+    let msg = MessageRef::from_bytes(b"\n\r\n\nsubject\nwrapped\n\nbody\n");
+    assert_eq!(
+        msg,
+        MessageRef {
+            title: b"subject\nwrapped".as_bstr(),
+            body: Some(b"body\n".as_bstr())
+        }
+    );
+    assert_eq!(msg.summary().as_ref(), "subject wrapped");
+}
+
+#[test]
+fn leading_lines_with_whitespace_are_not_skipped() {
+    let input = b" \n\nsubject\n\nbody\n";
+    let msg = MessageRef::from_bytes(input);
+    assert_eq!(
+        msg,
+        MessageRef {
+            title: b" ".as_bstr(),
+            body: Some(b"subject\n\nbody\n".as_bstr())
+        },
+        "only empty lines are skipped"
+    );
+    assert_eq!(msg.summary().as_ref(), "");
+    assert_eq!(
+        gix_object::commit::message::summary(input.as_bstr()).as_ref(),
+        "subject",
+        "the summary of the entire message skips all leading whitespace"
+    );
+}
+
+#[test]
+fn leading_whitespace_of_the_title_is_retained() {
+    let msg = MessageRef::from_bytes(b"\n\n  indented subject\n\nbody\n");
+    assert_eq!(
+        msg,
+        MessageRef {
+            title: b"  indented subject".as_bstr(),
+            body: Some(b"body\n".as_bstr())
+        }
+    );
+}
+
+#[test]
+fn body_of_entire_messages() {
+    use gix_object::commit::message::body;
+    assert_eq!(
+        body(b"subject".as_bstr()),
+        None,
+        "everything is subject without separator"
+    );
+    assert_eq!(body(b"subject\n\n".as_bstr()), None, "empty bodies are no bodies");
+    assert_eq!(
+        body(b"\n\nsubject\r\n\r\nbody\n\nsecond paragraph".as_bstr()),
+        Some(b"body\n\nsecond paragraph".as_bstr())
+    );
 }
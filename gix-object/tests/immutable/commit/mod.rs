@@ -146,6 +146,42 @@ mod method {
     }
 }
 
+mod headers {
+    use std::borrow::Cow;
+
+    use gix_object::bstr::ByteSlice;
+
+    use crate::immutable::{commit::MERGE_TAG, fixture_bytes};
+
+    #[test]
+    fn all_headers_are_returned_in_order_with_multi_line_values_unfolded() {
+        let input = fixture_bytes("commit", "mergetag.txt");
+        let headers = gix_object::commit::headers(&input).collect::<Vec<_>>();
+        let names = headers
+            .iter()
+            .map(|(name, _)| name.to_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["tree", "parent", "parent", "author", "committer", "mergetag"],
+            "iteration stops before the message"
+        );
+        assert_eq!(headers[0].1.as_ref(), "1c61918031bf2c7fab9e17dde3c52a6a9884fcb5");
+        assert!(
+            matches!(headers[0].1, Cow::Borrowed(_)),
+            "single-line values are not allocated"
+        );
+        assert_eq!(headers[5].1.as_ref(), MERGE_TAG.as_bytes().as_bstr());
+        assert!(matches!(headers[5].1, Cow::Owned(_)), "multi-line values are unfolded");
+    }
+
+    #[test]
+    fn iteration_stops_at_malformed_headers() {
+        assert_eq!(gix_object::commit::headers(b"tree\nparent abc\n").count(), 0);
+        assert_eq!(gix_object::commit::headers(b"").count(), 0);
+    }
+}
+
 mod from_bytes;
 mod iter;
 mod message;
@@ -141,4 +141,48 @@ mod entry_mode {
             "it should not change without notice"
         );
     }
+
+    #[test]
+    fn from_bytes_round_trips_canonical_modes() {
+        for mode in [
+            EntryMode::Tree,
+            EntryMode::Blob,
+            EntryMode::BlobExecutable,
+            EntryMode::Link,
+            EntryMode::Commit,
+        ] {
+            assert_eq!(EntryMode::from_bytes(mode.as_bytes()).expect("valid"), mode);
+        }
+    }
+
+    #[test]
+    fn from_bytes_accepts_known_non_canonical_blob_modes() {
+        for mode in [b"100664", b"100640"] {
+            assert_eq!(EntryMode::from_bytes(mode).expect("valid"), EntryMode::Blob);
+        }
+    }
+
+    #[test]
+    fn from_bytes_rejects_modes_git_would_not_accept() {
+        for mode in [&b"100777"[..], b"040000", b"100600", b"644", b"", b"1006440"] {
+            let err = EntryMode::from_bytes(mode).unwrap_err();
+            assert!(matches!(err, gix_object::tree::mode::Error::InvalidMode { mode: m } if m == mode));
+        }
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(EntryMode::Tree.is_tree());
+        assert!(!EntryMode::Tree.is_blob());
+        assert!(EntryMode::Blob.is_blob());
+        assert!(!EntryMode::Blob.is_executable());
+        assert!(EntryMode::BlobExecutable.is_blob());
+        assert!(EntryMode::BlobExecutable.is_executable());
+        assert!(EntryMode::Link.is_link());
+        assert!(!EntryMode::Link.is_blob());
+        assert!(EntryMode::Link.is_blob_or_symlink());
+        assert!(EntryMode::Commit.is_commit());
+        assert!(EntryMode::Commit.is_no_tree());
+        assert!(!EntryMode::Commit.is_blob_or_symlink());
+    }
 }
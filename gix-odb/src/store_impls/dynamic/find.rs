@@ -144,7 +144,7 @@ where
                         };
                         let entry = pack.entry(pack_offset);
                         let header_size = entry.header_size();
-                        let res = match pack.decode_entry(
+                        let res = match pack.decode_entry_with_limit(
                             entry,
                             buffer,
                             |id, _out| {
@@ -153,6 +153,7 @@ where
                                 })
                             },
                             pack_cache,
+                            self.max_delta_chain_len,
                         ) {
                             Ok(r) => Ok((
                                 gix_object::Data {
@@ -228,7 +229,7 @@ where
                                     .expect("pack to still be available like just now");
                                 let entry = pack.entry(pack_offset);
                                 let header_size = entry.header_size();
-                                pack.decode_entry(
+                                pack.decode_entry_with_limit(
                                     entry,
                                     buffer,
                                     |id, out| {
@@ -251,6 +252,7 @@ where
                                             })
                                     },
                                     pack_cache,
+                                    self.max_delta_chain_len,
                                 )
                                 .map(move |r| {
                                     (
@@ -259,6 +259,7 @@ impl super::Store {
             token: Some(token),
             snapshot: RefCell::new(self.collect_snapshot()),
             max_recursion_depth: Self::INITIAL_MAX_RECURSION_DEPTH,
+            max_delta_chain_len: None,
             packed_object_count: Default::default(),
         }
     }
@@ -275,6 +276,7 @@ impl super::Store {
             token: Some(token),
             snapshot: RefCell::new(self.collect_snapshot()),
             max_recursion_depth: Self::INITIAL_MAX_RECURSION_DEPTH,
+            max_delta_chain_len: None,
             packed_object_count: Default::default(),
         }
     }
@@ -363,6 +365,7 @@ impl super::Handle<Rc<super::Store>> {
         let mut cache = store.to_handle_arc();
         cache.refresh = self.refresh;
         cache.max_recursion_depth = self.max_recursion_depth;
+        cache.max_delta_chain_len = self.max_delta_chain_len;
         Ok(cache)
     }
 }
@@ -393,6 +396,7 @@ where
             },
             snapshot: RefCell::new(self.store.collect_snapshot()),
             max_recursion_depth: self.max_recursion_depth,
+            max_delta_chain_len: self.max_delta_chain_len,
             packed_object_count: Default::default(),
         }
     }
@@ -18,6 +18,13 @@ where
     /// This value doesn't have to be huge as in typical scenarios, these kind of objects are rare and chains supposedly are
     /// even more rare.
     pub max_recursion_depth: usize,
+    /// The maximum amount of deltas to resolve when decoding a single packed object, or `None` to resolve chains of any length.
+    ///
+    /// Setting this protects against purposefully crafted packs whose delta chains are so long that decoding them is very
+    /// expensive, making it useful for servers that accept packs from untrusted sources.
+    /// Note that the limit applies to each pack and each ref-delta base lookup separately, with the latter being
+    /// limited by [`max_recursion_depth`][Self::max_recursion_depth].
+    pub max_delta_chain_len: Option<usize>,
 
     /// If true, replacements will not be performed even if these are available.
     pub ignore_replacements: bool,
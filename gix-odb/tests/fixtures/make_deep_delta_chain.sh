@@ -0,0 +1,12 @@
+#!/bin/bash
+set -eu -o pipefail
+
+git init -q
+
+for round in $(seq 40); do
+  seq "$(( round * 20 ))" "$(( round * 20 + 1000 ))" > file
+  git add file
+  git commit -qm "$round"
+done
+
+git repack -adfq --depth=50 --window=50
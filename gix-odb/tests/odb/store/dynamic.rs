@@ -759,6 +759,113 @@ fn iterate_over_a_bunch_of_loose_and_packed_objects() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn max_delta_chain_len_limits_the_amount_of_deltas_to_resolve() -> crate::Result {
+    let dir = gix_testtools::scripted_fixture_read_only("make_deep_delta_chain.sh")?;
+    let mut handle = gix_odb::at(dir.join(".git").join("objects"))?;
+    let ids = handle.iter()?.collect::<Result<Vec<_>, _>>()?;
+    let mut buf = Vec::new();
+
+    handle.max_delta_chain_len = Some(50);
+    for id in &ids {
+        assert!(
+            handle.try_find(id, &mut buf)?.is_some(),
+            "the deepest chain has 39 deltas and is within the limit"
+        );
+    }
+
+    handle.max_delta_chain_len = Some(10);
+    let mut num_too_long = 0;
+    for id in &ids {
+        match handle.try_find(id, &mut buf) {
+            Ok(obj) => assert!(obj.is_some()),
+            Err(store::find::Error::Pack(gix_odb::pack::data::decode::Error::DeltaChainTooLong {
+                max_delta_chain_len: 10,
+            })) => num_too_long += 1,
+            Err(err) => panic!("unexpected error: {err}"),
+        }
+    }
+    assert_eq!(
+        num_too_long, 29,
+        "all blobs with more than 10 deltas in their chain can't be decoded anymore"
+    );
+    Ok(())
+}
+
+#[test]
+fn pack_cache_shortens_delta_chains_of_objects_sharing_their_bases() -> crate::Result {
+    #[derive(Default)]
+    struct CountingCache {
+        entries: std::collections::HashMap<(u32, u64), (Vec<u8>, gix_object::Kind, usize)>,
+        hits: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl gix_pack::cache::DecodeEntry for CountingCache {
+        fn put(&mut self, pack_id: u32, offset: u64, data: &[u8], kind: gix_object::Kind, compressed_size: usize) {
+            self.entries
+                .insert((pack_id, offset), (data.to_owned(), kind, compressed_size));
+        }
+
+        fn get(&mut self, pack_id: u32, offset: u64, out: &mut Vec<u8>) -> Option<(gix_object::Kind, usize)> {
+            let (data, kind, compressed_size) = self.entries.get(&(pack_id, offset))?;
+            self.hits.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            out.clear();
+            out.extend_from_slice(data);
+            Some((*kind, *compressed_size))
+        }
+    }
+
+    let dir = gix_testtools::scripted_fixture_read_only("make_deep_delta_chain.sh")?;
+    let mut handle = gix_odb::at(dir.join(".git").join("objects"))?;
+    handle.prevent_pack_unload();
+    let mut buf = Vec::new();
+    let mut ids = handle.iter()?.collect::<Result<Vec<_>, _>>()?;
+    ids.sort_by_key(|id| {
+        gix_pack::Find::location_by_oid(&handle, id, &mut buf)
+            .expect("all objects are packed")
+            .pack_offset
+    });
+    handle.max_delta_chain_len = Some(1);
+    let mut num_too_long = 0;
+    for id in &ids {
+        match handle.try_find(id, &mut buf) {
+            Ok(obj) => assert!(obj.is_some()),
+            Err(store::find::Error::Pack(gix_odb::pack::data::decode::Error::DeltaChainTooLong {
+                max_delta_chain_len: 1,
+            })) => num_too_long += 1,
+            Err(err) => panic!("unexpected error: {err}"),
+        }
+    }
+    assert_eq!(
+        num_too_long, 38,
+        "without a cache, all deltified blobs but the first one have to resolve more than one delta"
+    );
+
+    let hits = std::sync::Arc::new(std::sync::atomic::AtomicUsize::default());
+    handle.set_pack_cache({
+        let hits = hits.clone();
+        move || {
+            Box::new(CountingCache {
+                hits: hits.clone(),
+                ..Default::default()
+            })
+        }
+    });
+
+    for id in &ids {
+        assert!(
+            handle.try_find(id, &mut buf)?.is_some(),
+            "bases are stored before their deltas, so each base was just decoded and can be taken from the cache"
+        );
+    }
+    assert_eq!(
+        hits.load(std::sync::atomic::Ordering::SeqCst),
+        38,
+        "all 39 deltified blobs but the first one, whose base isn't a delta, take their base from the cache"
+    );
+    Ok(())
+}
+
 #[test]
 fn auto_refresh_with_and_without_id_stability() -> crate::Result {
     let tmp = gix_testtools::tempfile::TempDir::new()?;
@@ -161,6 +161,23 @@ impl File {
         out: &mut Vec<u8>,
         resolve: impl Fn(&gix_hash::oid, &mut Vec<u8>) -> Option<ResolvedBase>,
         delta_cache: &mut impl cache::DecodeEntry,
+    ) -> Result<Outcome, Error> {
+        self.decode_entry_with_limit(entry, out, resolve, delta_cache, None)
+    }
+
+    /// Like [`decode_entry()`][File::decode_entry()], but fail with [`Error::DeltaChainTooLong`] if more than
+    /// `max_delta_chain_len` deltas would have to be resolved to produce the object, or decode any chain if it is `None`.
+    ///
+    /// This protects against purposefully crafted packs with very long, and thus very expensive, delta chains.
+    /// Note that deltas with bases in the `delta_cache` don't count towards the limit, and neither do the deltas
+    /// of bases returned as [`ResolvedBase::OutOfPack`] by `resolve`.
+    pub fn decode_entry_with_limit(
+        &self,
+        entry: data::Entry,
+        out: &mut Vec<u8>,
+        resolve: impl Fn(&gix_hash::oid, &mut Vec<u8>) -> Option<ResolvedBase>,
+        delta_cache: &mut impl cache::DecodeEntry,
+        max_delta_chain_len: Option<usize>,
     ) -> Result<Outcome, Error> {
         use crate::data::entry::Header::*;
         match entry.header {
@@ -180,7 +197,9 @@ impl File {
                     )
                 })
             }
-            OfsDelta { .. } | RefDelta { .. } => self.resolve_deltas(entry, resolve, out, delta_cache),
+            OfsDelta { .. } | RefDelta { .. } => {
+                self.resolve_deltas(entry, resolve, out, delta_cache, max_delta_chain_len)
+            }
         }
     }
 
@@ -193,6 +212,7 @@ impl File {
         resolve: impl Fn(&gix_hash::oid, &mut Vec<u8>) -> Option<ResolvedBase>,
         out: &mut Vec<u8>,
         cache: &mut impl cache::DecodeEntry,
+        max_delta_chain_len: Option<usize>,
     ) -> Result<Outcome, Error> {
         // all deltas, from the one that produces the desired object (first) to the oldest at the end of the chain
         let mut chain = SmallVec::<[Delta; 10]>::default();
@@ -215,6 +235,9 @@ impl File {
                 }
                 break;
             }
+            if let Some(max_delta_chain_len) = max_delta_chain_len.filter(|max| chain.len() >= *max) {
+                return Err(Error::DeltaChainTooLong { max_delta_chain_len });
+            }
             total_delta_data_size += cursor.decompressed_size;
             let decompressed_size = cursor
                 .decompressed_size
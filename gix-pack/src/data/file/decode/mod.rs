@@ -13,4 +13,6 @@ pub enum Error {
     ZlibInflate(#[from] gix_features::zlib::inflate::Error),
     #[error("A delta chain could not be followed as the ref base with id {0} could not be found")]
     DeltaBaseUnresolved(gix_hash::ObjectId),
+    #[error("The delta chain is longer than the maximum of {max_delta_chain_len} deltas")]
+    DeltaChainTooLong { max_delta_chain_len: usize },
 }
@@ -42,9 +42,7 @@ pub fn checksum_on_disk_or_mmap(
         should_interrupt,
     ) {
         Ok(id) => id,
-        Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {
-            return Err(checksum::Error::Interrupted)
-        }
+        Err(err) if err.kind() == std::io::ErrorKind::Interrupted => return Err(checksum::Error::Interrupted),
         Err(_io_err) => bar(data, object_hash, progress, data_len_without_trailer),
     };
     if actual == expected {
@@ -56,7 +54,7 @@ pub fn checksum_on_disk_or_mmap(
 fn bar<'lt0>(
     data: &'lt0 [u8],
     object_hash: Kind,
-    progress: impl Progress<SubProgress = _> + Sized,
+    mut progress: impl Progress + Sized,
     data_len_without_trailer: usize,
 ) -> ObjectId {
     let start = std::time::Instant::now();
@@ -2,20 +2,37 @@
 pub mod name {
     use std::convert::Infallible;
 
-    /// The error used in [name()][super::name()] and [name_partial()][super::name_partial()]
+    use bstr::BString;
+
+    /// The error used in [name()][super::name()], [name_loose()][super::name_loose()], [name_partial()][super::name_partial()]
+    /// [name_with_limits()][super::name_with_limits()] and [symbolic_target()][super::symbolic_target()]
     #[derive(Debug, thiserror::Error)]
     #[allow(missing_docs)]
     pub enum Error {
         #[error("A reference must be a valid tag name as well")]
         Tag(#[from] crate::tag::name::Error),
+        #[error("A reference must not contain control characters, spaces or any of '~^:?*[\\', found {byte:?} at position {position}")]
+        InvalidByte { byte: BString, position: usize },
         #[error("Standalone references must be all uppercased, like 'HEAD'")]
         SomeLowercase,
         #[error("A reference name must not start with a slash '/'")]
         StartsWithSlash,
+        #[error("A reference name must not end with a slash '/'")]
+        EndsWithSlash,
         #[error("Multiple slashes in a row are not allowed as they may change the reference's meaning")]
         RepeatedSlash,
         #[error("Names must not be a single '.', but may contain it.")]
         SingleDot,
+        #[error("A path component must not end with '.lock'")]
+        EndsWithDotLock,
+        #[error("A reference name must not contain '@{{' as it's used to refer to reflog entries and more")]
+        ReservedAtBrace,
+        #[error("A symbolic reference target must start with 'ref: '")]
+        MissingSymbolicRefPrefix,
+        #[error("A reference name must not be longer than {max_bytes} bytes, got {actual}")]
+        TooLong { max_bytes: usize, actual: usize },
+        #[error("A reference name must not have more than {max_components} components, got {actual}")]
+        TooManyComponents { max_components: usize, actual: usize },
     }
 
     impl From<Infallible> for Error {
@@ -25,7 +42,7 @@ pub mod name {
     }
 }
 
-use bstr::BStr;
+use bstr::{BStr, BString, ByteSlice};
 
 /// Validate a reference name running all the tests in the book. This disallows lower-case references, but allows
 /// ones like `HEAD`.
@@ -33,30 +50,158 @@ pub fn name(path: &BStr) -> Result<&BStr, name::Error> {
     validate(path, Mode::Complete)
 }
 
+/// Limits to impose on reference names when validating them with [`name_with_limits()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Limits {
+    /// The maximum amount of bytes a reference name may have, or `None` if unlimited.
+    pub max_bytes: Option<usize>,
+    /// The maximum amount of `/` separated components a reference name may have, or `None` if unlimited.
+    ///
+    /// `refs/heads/main` has 3 components.
+    pub max_components: Option<usize>,
+}
+
+impl Limits {
+    /// Impose no limits, which is what [`name()`] does.
+    pub fn none() -> Self {
+        Limits::default()
+    }
+}
+
+/// Validate a reference name like [`name()`], but also assure it stays within the given `limits`.
+///
+/// This is useful to prevent pathological names which may cause issues with file systems once they are stored as loose
+/// references.
+pub fn name_with_limits(path: &BStr, limits: Limits) -> Result<&BStr, name::Error> {
+    let path = name(path)?;
+    if let Some(max_bytes) = limits.max_bytes {
+        if path.len() > max_bytes {
+            return Err(name::Error::TooLong {
+                max_bytes,
+                actual: path.len(),
+            });
+        }
+    }
+    if let Some(max_components) = limits.max_components {
+        let actual = path.split_str("/").count();
+        if actual > max_components {
+            return Err(name::Error::TooManyComponents { max_components, actual });
+        }
+    }
+    Ok(path)
+}
+
 /// Validate a partial reference name. As it is assumed to be partial, names like `some-name` is allowed
 /// even though these would be disallowed with when using [`name()`].
 pub fn name_partial(path: &BStr) -> Result<&BStr, name::Error> {
     validate(path, Mode::Partial)
 }
 
+/// Validate a complete reference name like [`name()`], but also allow names without a slash that aren't all uppercased,
+/// like `main`.
+///
+/// This deviates from git's rule that a reference name must contain at least one `/` unless it's a pseudo-ref like `HEAD`,
+/// similar to what `git check-ref-format --allow-onelevel` does. Only use it if `path` is known not to be a pseudo-ref.
+///
+/// As this is the only rule that sets complete names apart from partial ones, this is an alias for [`name_partial()`].
+pub fn name_loose(path: &BStr) -> Result<&BStr, name::Error> {
+    name_partial(path)
+}
+
+/// Normalize `path` and validate it like [`name()`], returning the normalized reference name as owned copy so it can be stored.
+///
+/// Normalization only removes a single trailing `/`, so `refs/heads/main/` becomes `refs/heads/main`. Git doesn't collapse
+/// any other components, which is why all other rules apply as usual and cause an error. Prefer [`name()`] if `path` doesn't
+/// have to be stored as it doesn't allocate.
+pub fn normalize(path: &BStr) -> Result<BString, name::Error> {
+    let path = path.strip_suffix(b"/").map_or(path, |p| p.as_bstr());
+    name(path).map(ToOwned::to_owned)
+}
+
+/// Validate the target of a symbolic reference, like `ref: refs/heads/main` as stored in the `HEAD` file, and return the
+/// name of the reference it points to.
+///
+/// The `ref: ` prefix is optional, unless `strict` is `true` in which case its absence is an error. A single trailing newline
+/// is ignored. The remaining name must be valid according to [`name()`].
+pub fn symbolic_target(input: &BStr, strict: bool) -> Result<&BStr, name::Error> {
+    let input = input
+        .strip_suffix(b"\n")
+        .map(|input| input.strip_suffix(b"\r").unwrap_or(input))
+        .unwrap_or(input);
+    let target = match input.strip_prefix(b"ref: ") {
+        Some(target) => target,
+        None if strict => return Err(name::Error::MissingSymbolicRefPrefix),
+        None => input,
+    };
+    name(target.as_bstr())
+}
+
+/// The kind of reference a partial name is [expanded][expand_partial()] to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Category {
+    /// A branch in `refs/heads/`.
+    LocalBranch,
+    /// A branch of a remote in `refs/remotes/`.
+    RemoteBranch,
+    /// A tag in `refs/tags/`.
+    Tag,
+    /// A note in `refs/notes/`.
+    Note,
+}
+
+impl Category {
+    /// Return the prefix of all references of this category, like `refs/heads/`.
+    pub fn prefix(&self) -> &'static BStr {
+        match self {
+            Category::LocalBranch => b"refs/heads/".as_bstr(),
+            Category::RemoteBranch => b"refs/remotes/".as_bstr(),
+            Category::Tag => b"refs/tags/".as_bstr(),
+            Category::Note => b"refs/notes/".as_bstr(),
+        }
+    }
+}
+
+/// Validate the partial reference name `path` like [`name_partial()`] and turn it into a full reference name of the given
+/// `category`, so `main` becomes `refs/heads/main` for [local branches][Category::LocalBranch].
+///
+/// Names that already start with `refs/` are considered fully qualified and returned unchanged.
+pub fn expand_partial(path: &BStr, category: Category) -> Result<BString, name::Error> {
+    let path = name_partial(path)?;
+    if path.starts_with(b"refs/") {
+        return Ok(path.to_owned());
+    }
+    let mut out: BString = category.prefix().to_owned();
+    out.extend_from_slice(path);
+    Ok(out)
+}
+
 enum Mode {
     Complete,
     Partial,
 }
 
 fn validate(path: &BStr, mode: Mode) -> Result<&BStr, name::Error> {
-    crate::tagname(path)?;
-    if path[0] == b'/' {
-        return Err(name::Error::StartsWithSlash);
-    }
+    // Forbidden bytes, `@{` and `.lock` suffixes are reported before anything else, while all other errors keep their precedence.
+    let mut first_error = None;
     let mut previous = 0;
     let mut one_before_previous = 0;
     let mut saw_slash = false;
-    for byte in path.iter() {
-        match *byte {
-            b'/' if previous == b'.' && one_before_previous == b'/' => return Err(name::Error::SingleDot),
-            b'/' if previous == b'/' => return Err(name::Error::RepeatedSlash),
-            _ => {}
+    for (pos, byte) in path.iter().enumerate() {
+        let error = match *byte {
+            b if crate::tag::is_invalid_byte(b) || b == b'*' => {
+                return Err(name::Error::InvalidByte {
+                    byte: (&path[pos..][..1]).into(),
+                    position: pos,
+                })
+            }
+            b'{' if previous == b'@' => return Err(name::Error::ReservedAtBrace),
+            b'/' if path[..pos].ends_with(b".lock") => return Err(name::Error::EndsWithDotLock),
+            b'/' if previous == b'.' && one_before_previous == b'/' => Some(name::Error::SingleDot),
+            b'/' if previous == b'/' => Some(name::Error::RepeatedSlash),
+            _ => None,
+        };
+        if first_error.is_none() {
+            first_error = error;
         }
 
         if *byte == b'/' {
@@ -65,6 +210,22 @@ fn validate(path: &BStr, mode: Mode) -> Result<&BStr, name::Error> {
         one_before_previous = previous;
         previous = *byte;
     }
+    if path.ends_with(b".lock") {
+        return Err(name::Error::EndsWithDotLock);
+    }
+    if path.ends_with(b"/") {
+        return Err(name::Error::EndsWithSlash);
+    }
+    crate::tagname(path)?;
+    if path[0] == b'/' {
+        return Err(name::Error::StartsWithSlash);
+    }
+    if let Some(error) = first_error {
+        return Err(error);
+    }
+    if previous == b'.' && one_before_previous == b'/' {
+        return Err(name::Error::SingleDot);
+    }
 
     if let Mode::Complete = mode {
         if !saw_slash && !path.iter().all(|c| c.is_ascii_uppercase() || *c == b'_') {
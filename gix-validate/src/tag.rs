@@ -39,7 +39,7 @@ pub fn name(input: &BStr) -> Result<&BStr, name::Error> {
     let mut previous = 0;
     for byte in input.iter() {
         match byte {
-            b'\\' | b'^' | b':' | b'[' | b'?' | b' ' | b'~' | b'\0'..=b'\x1F' | b'\x7F' => {
+            b if is_invalid_byte(*b) => {
                 return Err(name::Error::InvalidByte {
                     byte: (&[*byte][..]).into(),
                 })
@@ -59,3 +59,12 @@ pub fn name(input: &BStr) -> Result<&BStr, name::Error> {
     }
     Ok(input)
 }
+
+/// Bytes that git never allows in tag and reference names as they have special meaning in revision specs or may confuse
+/// parsers of files that contain references.
+pub(crate) fn is_invalid_byte(b: u8) -> bool {
+    matches!(
+        b,
+        b'\\' | b'^' | b':' | b'[' | b'?' | b' ' | b'~' | b'\0'..=b'\x1F' | b'\x7F'
+    )
+}
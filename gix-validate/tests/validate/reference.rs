@@ -60,11 +60,7 @@ mod name_partial {
         mktest!(any_path_starts_with_slash, b"/etc/foo", RefError::StartsWithSlash);
         mktest!(empty_path, b"", RefError::Tag(TagError::Empty));
         mktest!(refs_starts_with_slash, b"/refs/heads/main", RefError::StartsWithSlash);
-        mktest!(
-            ends_with_slash,
-            b"refs/heads/main/",
-            RefError::Tag(TagError::EndsWithSlash)
-        );
+        mktest!(ends_with_slash, b"refs/heads/main/", RefError::EndsWithSlash);
         mktest!(
             path_with_duplicate_slashes,
             b"refs//heads/main",
@@ -73,12 +69,80 @@ mod name_partial {
         mktest!(
             path_with_spaces,
             b"refs//heads/name with spaces",
-            RefError::Tag(TagError::InvalidByte { .. })
+            RefError::InvalidByte { position: 16, .. }
         );
         mktest!(
             path_with_backslashes,
             b"refs\\heads/name with spaces",
-            RefError::Tag(TagError::InvalidByte { .. })
+            RefError::InvalidByte { position: 4, .. }
+        );
+        mktest!(
+            path_component_ends_with_dot_lock,
+            b"a/b.lock/c",
+            RefError::EndsWithDotLock
+        );
+        mktest!(
+            last_path_component_ends_with_dot_lock,
+            b"refs/heads/foo.lock",
+            RefError::EndsWithDotLock
+        );
+        mktest!(
+            contains_reflog_portion,
+            b"refs/heads/foo@{1}",
+            RefError::ReservedAtBrace
+        );
+        mktest!(
+            last_path_component_is_singular_dot,
+            b"refs/heads/.",
+            RefError::SingleDot
+        );
+    }
+}
+
+mod name_loose {
+    mod valid {
+        use bstr::ByteSlice;
+        macro_rules! mktest {
+            ($name:ident, $input:expr) => {
+                #[test]
+                fn $name() {
+                    assert!(gix_validate::reference::name_loose($input.as_bstr()).is_ok())
+                }
+            };
+        }
+
+        mktest!(refs_path, b"refs/heads/main");
+        mktest!(all_uppercase, b"HEAD");
+        mktest!(lowercase_name_without_path, b"main");
+        mktest!(capitalized_name_without_path, b"Main");
+    }
+
+    mod invalid {
+        use bstr::ByteSlice;
+        use gix_validate::{reference::name::Error as RefError, tag::name::Error as TagError};
+
+        macro_rules! mktest {
+            ($name:ident, $input:literal, $expected:pat) => {
+                #[test]
+                fn $name() {
+                    match gix_validate::reference::name_loose($input.as_bstr()) {
+                        Err($expected) => {}
+                        got => panic!("Wanted {}, got {:?}", stringify!($expected), got),
+                    }
+                }
+            };
+        }
+
+        mktest!(
+            refs_path_double_dot,
+            b"refs/../somewhere",
+            RefError::Tag(TagError::DoubleDot)
+        );
+        mktest!(any_path_starts_with_slash, b"/etc/foo", RefError::StartsWithSlash);
+        mktest!(
+            a_path_with_duplicate_slashes,
+            b"refs//heads/main",
+            RefError::RepeatedSlash
         );
     }
 }
@@ -143,18 +207,323 @@ mod name {
         );
         mktest!(capitalized_name_without_path, b"Main", RefError::SomeLowercase);
         mktest!(lowercase_name_without_path, b"main", RefError::SomeLowercase);
+        mktest!(name_ends_with_dot_lock, b"foo.lock", RefError::EndsWithDotLock);
         mktest!(any_path_starts_with_slash, b"/etc/foo", RefError::StartsWithSlash);
         mktest!(empty_path, b"", RefError::Tag(TagError::Empty));
         mktest!(refs_starts_with_slash, b"/refs/heads/main", RefError::StartsWithSlash);
-        mktest!(
-            ends_with_slash,
-            b"refs/heads/main/",
-            RefError::Tag(TagError::EndsWithSlash)
-        );
+        mktest!(ends_with_slash, b"refs/heads/main/", RefError::EndsWithSlash);
         mktest!(
             a_path_with_duplicate_slashes,
             b"refs//heads/main",
             RefError::RepeatedSlash
         );
+        mktest!(
+            path_component_ends_with_dot_lock,
+            b"a/b.lock/c",
+            RefError::EndsWithDotLock
+        );
+        mktest!(
+            last_path_component_ends_with_dot_lock,
+            b"refs/heads/foo.lock",
+            RefError::EndsWithDotLock
+        );
+        mktest!(
+            contains_reflog_portion,
+            b"refs/heads/foo@{1}",
+            RefError::ReservedAtBrace
+        );
+        mktest!(
+            last_path_component_is_singular_dot,
+            b"refs/heads/.",
+            RefError::SingleDot
+        );
+    }
+}
+
+mod invalid_bytes {
+    use bstr::ByteSlice;
+    use gix_validate::reference::name::Error as RefError;
+
+    type Validator = fn(&bstr::BStr) -> Result<&bstr::BStr, RefError>;
+
+    #[test]
+    fn are_rejected_with_position_in_all_validation_modes() {
+        let invalid = (b'\0'..=b'\x1F').chain(b" ~^:?*[\\\x7F".iter().copied());
+        let validators: [Validator; 3] = [
+            gix_validate::reference::name,
+            gix_validate::reference::name_loose,
+            gix_validate::reference::name_partial,
+        ];
+        for byte in invalid {
+            let mut name = b"refs/heads/a".to_vec();
+            name.push(byte);
+            name.extend_from_slice(b"b");
+            for validate in validators {
+                match validate(name.as_bstr()) {
+                    Err(RefError::InvalidByte { byte: actual, position }) => {
+                        assert_eq!(actual, [byte].as_bstr());
+                        assert_eq!(position, 12);
+                    }
+                    got => panic!("Wanted InvalidByte for {byte:?}, got {got:?}"),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn name_and_name_partial_agree_on_paths_with_slashes() {
+    for input in [
+        "refs/heads/main",
+        "refs/heads/",
+        "refs/heads/.",
+        "refs/./heads",
+        "refs//heads",
+        "/refs/heads",
+        "refs/heads/a.lock/b",
+        "refs/heads/a b",
+    ] {
+        let input = input.into();
+        assert_eq!(
+            format!("{:?}", gix_validate::reference::name(input)),
+            format!("{:?}", gix_validate::reference::name_partial(input)),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn name_loose_is_name_partial() {
+    for input in [
+        "refs/heads/main",
+        "main",
+        "Main",
+        "HEAD",
+        "main/",
+        "main.lock",
+        "/main",
+        "a b",
+    ] {
+        let input = input.into();
+        assert_eq!(
+            format!("{:?}", gix_validate::reference::name_loose(input)),
+            format!("{:?}", gix_validate::reference::name_partial(input)),
+            "{input}"
+        );
+    }
+}
+
+mod normalize {
+    use bstr::ByteSlice;
+    use gix_validate::{reference::name::Error as RefError, tag::name::Error as TagError};
+
+    #[test]
+    fn a_single_trailing_slash_is_removed() {
+        assert_eq!(
+            gix_validate::reference::normalize(b"refs/heads/main/".as_bstr()).unwrap(),
+            "refs/heads/main"
+        );
+        assert_eq!(
+            gix_validate::reference::normalize(b"refs/heads/main".as_bstr()).unwrap(),
+            "refs/heads/main"
+        );
+        assert_eq!(gix_validate::reference::normalize(b"HEAD".as_bstr()).unwrap(), "HEAD");
+    }
+
+    #[test]
+    fn the_normalized_name_is_validated() {
+        assert!(matches!(
+            gix_validate::reference::normalize(b"refs/heads/main//".as_bstr()),
+            Err(RefError::EndsWithSlash)
+        ));
+        assert!(matches!(
+            gix_validate::reference::normalize(b"refs/heads/./".as_bstr()),
+            Err(RefError::SingleDot)
+        ));
+        assert!(matches!(
+            gix_validate::reference::normalize(b"main/".as_bstr()),
+            Err(RefError::SomeLowercase)
+        ));
+        assert!(matches!(
+            gix_validate::reference::normalize(b"/".as_bstr()),
+            Err(RefError::Tag(TagError::Empty))
+        ));
+    }
+}
+
+mod expand_partial {
+    use bstr::ByteSlice;
+    use gix_validate::reference::{expand_partial, name::Error as RefError, Category};
+
+    #[test]
+    fn the_category_prefix_is_prepended() {
+        for (category, expected) in [
+            (Category::LocalBranch, "refs/heads/main"),
+            (Category::RemoteBranch, "refs/remotes/main"),
+            (Category::Tag, "refs/tags/main"),
+            (Category::Note, "refs/notes/main"),
+        ] {
+            assert_eq!(expand_partial(b"main".as_bstr(), category).unwrap(), expected);
+        }
+        assert_eq!(
+            expand_partial(b"origin/main".as_bstr(), Category::RemoteBranch).unwrap(),
+            "refs/remotes/origin/main"
+        );
+    }
+
+    #[test]
+    fn fully_qualified_names_are_returned_unchanged() {
+        assert_eq!(
+            expand_partial(b"refs/heads/main".as_bstr(), Category::Tag).unwrap(),
+            "refs/heads/main"
+        );
+    }
+
+    #[test]
+    fn the_partial_name_is_validated() {
+        assert!(matches!(
+            expand_partial(b"main.lock/a".as_bstr(), Category::LocalBranch),
+            Err(RefError::EndsWithDotLock)
+        ));
+        assert!(matches!(
+            expand_partial(b"/main".as_bstr(), Category::LocalBranch),
+            Err(RefError::StartsWithSlash)
+        ));
+    }
+}
+
+mod symbolic_target {
+    use bstr::ByteSlice;
+    use gix_validate::{
+        reference::{name::Error as RefError, symbolic_target},
+        tag::name::Error as TagError,
+    };
+
+    #[test]
+    fn valid() {
+        for strict in [false, true] {
+            assert_eq!(
+                symbolic_target(b"ref: refs/heads/main".as_bstr(), strict).unwrap(),
+                "refs/heads/main"
+            );
+            assert_eq!(
+                symbolic_target(b"ref: refs/heads/main\n".as_bstr(), strict).unwrap(),
+                "refs/heads/main",
+                "a trailing newline as found in files is ignored"
+            );
+            assert_eq!(
+                symbolic_target(b"ref: refs/heads/main\r\n".as_bstr(), strict).unwrap(),
+                "refs/heads/main"
+            );
+        }
+        assert_eq!(
+            symbolic_target(b"refs/heads/main".as_bstr(), false).unwrap(),
+            "refs/heads/main",
+            "the prefix is optional unless strict"
+        );
+    }
+
+    #[test]
+    fn missing_prefix_in_strict_mode() {
+        assert!(matches!(
+            symbolic_target(b"refs/heads/main".as_bstr(), true),
+            Err(RefError::MissingSymbolicRefPrefix)
+        ));
+        assert!(matches!(
+            symbolic_target(b"ref:refs/heads/main".as_bstr(), true),
+            Err(RefError::MissingSymbolicRefPrefix)
+        ));
+    }
+
+    #[test]
+    fn malformed_targets() {
+        assert!(matches!(
+            symbolic_target(b"ref: main".as_bstr(), false),
+            Err(RefError::SomeLowercase)
+        ));
+        assert!(matches!(
+            symbolic_target(b"ref: refs/heads/a b".as_bstr(), false),
+            Err(RefError::InvalidByte { position: 12, .. })
+        ));
+        assert!(matches!(
+            symbolic_target(b"ref: ".as_bstr(), false),
+            Err(RefError::Tag(TagError::Empty))
+        ));
+        assert!(matches!(
+            symbolic_target(b"ref:refs/heads/main".as_bstr(), false),
+            Err(RefError::InvalidByte { position: 3, .. })
+        ));
+        assert!(matches!(
+            symbolic_target(b"ref: refs/heads/main\n\n".as_bstr(), false),
+            Err(RefError::InvalidByte { .. })
+        ));
+    }
+}
+
+mod name_with_limits {
+    use bstr::ByteSlice;
+    use gix_validate::reference::{name::Error as RefError, name_with_limits, Limits};
+
+    #[test]
+    fn no_limits_behave_like_name() {
+        assert_eq!(
+            name_with_limits(b"refs/heads/a/b/c/d".as_bstr(), Limits::none()).unwrap(),
+            "refs/heads/a/b/c/d"
+        );
+        assert!(matches!(
+            name_with_limits(b"main".as_bstr(), Limits::none()),
+            Err(RefError::SomeLowercase)
+        ));
+    }
+
+    #[test]
+    fn max_bytes() {
+        let limits = Limits {
+            max_bytes: Some(15),
+            ..Default::default()
+        };
+        assert_eq!(
+            name_with_limits(b"refs/heads/main".as_bstr(), limits).unwrap(),
+            "refs/heads/main"
+        );
+        assert!(matches!(
+            name_with_limits(b"refs/heads/mainx".as_bstr(), limits),
+            Err(RefError::TooLong {
+                max_bytes: 15,
+                actual: 16
+            })
+        ));
+    }
+
+    #[test]
+    fn max_components() {
+        let limits = Limits {
+            max_components: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            name_with_limits(b"refs/heads/main".as_bstr(), limits).unwrap(),
+            "refs/heads/main"
+        );
+        assert_eq!(name_with_limits(b"HEAD".as_bstr(), limits).unwrap(), "HEAD");
+        assert!(matches!(
+            name_with_limits(b"refs/heads/feature/main".as_bstr(), limits),
+            Err(RefError::TooManyComponents {
+                max_components: 3,
+                actual: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn names_are_validated_before_limits_are_checked() {
+        let limits = Limits {
+            max_bytes: Some(1),
+            max_components: Some(1),
+        };
+        assert!(matches!(
+            name_with_limits(b"refs//heads".as_bstr(), limits),
+            Err(RefError::RepeatedSlash)
+        ));
     }
 }
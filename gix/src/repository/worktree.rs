@@ -82,6 +82,9 @@ impl crate::Repository {
                 thread_limit,
                 min_extension_block_in_bytes_for_threading: 0,
                 expected_checksum: None,
+                extensions: Default::default(),
+                trailing_bytes: Default::default(),
+                verify_checksum: gix_index::decode::Checksum::Skip,
             },
         )
         .map_err(Into::into)